@@ -7,11 +7,15 @@ pub struct CommandResult {
 
 pub struct Command {
     pub string: String,
+    pub stdin: Option<Vec<u8>>,
 }
 
 impl Command {
     pub fn new(s: &str) -> Command {
-        Command { string: s.into() }
+        Command {
+            string: s.into(),
+            stdin: None,
+        }
     }
 
     pub fn and(&mut self, s: &str) -> &Command {
@@ -31,6 +35,13 @@ impl Command {
         self.string += &c;
         self
     }
+
+    // Data fed to the command's standard input, for contents which should
+    // not end up in the command line.
+    pub fn stdin(&mut self, data: &[u8]) -> &Command {
+        self.stdin = Some(data.to_vec());
+        self
+    }
 }
 
 impl<'a> From<&'a str> for Command {
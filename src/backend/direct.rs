@@ -8,8 +8,10 @@ use crate::provider::error::Error;
 use crate::provider::HandleFunc;
 use crate::provider::Output;
 
-use std::process::Command;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
 use std::result::Result;
+use std::thread;
 
 pub struct Direct;
 
@@ -47,7 +49,34 @@ impl Backend for Direct {
     }
 
     fn run_command(&self, c: command::Command) -> Result<CommandResult, backend::error::Error> {
-        let out = Command::new("sh").args(&["-c", &c.string]).output()?;
+        let mut sh = Command::new("sh");
+        sh.args(&["-c", &c.string]);
+
+        let out = match c.stdin {
+            Some(data) => {
+                let mut child = sh
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped())
+                    .spawn()?;
+                // The input is written from another thread while the output
+                // is read here, so that neither side blocks on a full pipe.
+                // A command which exits without reading its input is
+                // reported by its exit status below.
+                let writer = child.stdin.take().map(|mut stdin| {
+                    thread::spawn(move || match stdin.write_all(&data) {
+                        Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+                        r => r,
+                    })
+                });
+                let out = child.wait_with_output()?;
+                if let Some(w) = writer {
+                    w.join().expect("stdin writer panicked")?;
+                }
+                out
+            }
+            None => sh.output()?,
+        };
 
         if !out.status.success() {
             let e = backend::error::CommandError {
@@ -116,6 +116,11 @@ impl Backend for SSH {
         let mut chan = self.session.channel_session()?;
         chan.exec(&c.string).unwrap();
 
+        if let Some(ref data) = c.stdin {
+            chan.write_all(data)?;
+            chan.send_eof()?;
+        }
+
         let mut stdout = String::new();
         chan.read_to_string(&mut stdout).unwrap();
 
@@ -1,6 +1,8 @@
 extern crate libc;
 extern crate md5;
 extern crate nix;
+extern crate rusqlite;
+extern crate serde_json;
 extern crate sha2;
 extern crate uname;
 extern crate users;
@@ -14,11 +16,25 @@ use backend::Backend;
 use platform::error::DetectError;
 use platform::error::Error;
 use platform::platform::Platform;
+use provider::package::PackageKind;
 use provider::Providers;
+use resource::bond::Bond;
+use resource::bridge::Bridge;
+use resource::cgroup::Cgroup;
+use resource::docker_container::DockerContainer;
+use resource::docker_image::DockerImage;
 use resource::file::File;
+use resource::host::Host;
+use resource::linux_audit_system::LinuxAuditSystem;
+use resource::mail_alias::MailAlias;
+use resource::package;
 use resource::package::Package;
+use resource::package::PackageInfo;
 use resource::port::Port;
+use resource::repository::Repository;
+use resource::service;
 use resource::service::Service;
+use resource::service::ServiceInfo;
 
 pub struct Specinfra<'a> {
     pub backend: &'a dyn Backend,
@@ -26,7 +42,7 @@ pub struct Specinfra<'a> {
     pub providers: Box<Providers>,
 }
 
-pub fn new(b: &dyn Backend) -> Result<Specinfra, Error> {
+pub fn new(b: &dyn Backend) -> Result<Specinfra<'_>, Error> {
     let p = b.detect_platform().ok_or(DetectError {
         message: "Failed to detect platform".to_string(),
     })?;
@@ -39,19 +55,87 @@ pub fn new(b: &dyn Backend) -> Result<Specinfra, Error> {
 }
 
 impl<'a> Specinfra<'a> {
-    pub fn file(&self, name: &'static str) -> File {
+    pub fn file(&self, name: &'static str) -> File<'_> {
         File::new(name, self.backend, &self.providers.file)
     }
 
-    pub fn service(&self, name: &'static str) -> Service {
+    pub fn bond(&self, name: &'static str) -> Bond<'_> {
+        Bond::new(name, self.backend, &self.providers.bond)
+    }
+
+    pub fn bridge(&self, name: &'static str) -> Bridge<'_> {
+        Bridge::new(name, self.backend, &self.providers.bridge)
+    }
+
+    pub fn cgroup(&self, name: &'static str) -> Cgroup<'_> {
+        Cgroup::new(name, self.backend, &self.providers.cgroup)
+    }
+
+    pub fn docker_container(&self, name: &'static str) -> DockerContainer<'_> {
+        DockerContainer::new(name, self.backend, &self.providers.docker)
+    }
+
+    pub fn docker_image(&self, name: &'static str) -> DockerImage<'_> {
+        DockerImage::new(name, self.backend, &self.providers.docker)
+    }
+
+    pub fn host(&self, name: &'static str) -> Host<'_> {
+        Host::new(name, self.backend, &self.providers.host)
+    }
+
+    pub fn linux_audit_system(&self) -> LinuxAuditSystem<'_> {
+        LinuxAuditSystem::new(self.backend, &self.providers.linux_audit_system)
+    }
+
+    pub fn mail_alias(&self, name: &'static str) -> MailAlias<'_> {
+        MailAlias::new(name, self.backend, &self.providers.file)
+    }
+
+    pub fn repository(&self, name: &'static str) -> Repository<'_> {
+        Repository::new(name, self.backend, &self.providers.repository)
+    }
+
+    pub fn service(&self, name: &'static str) -> Service<'_> {
         Service::new(name, self.backend, &self.providers.service)
     }
 
-    pub fn package(&self, name: &'static str, version: Option<&'static str>) -> Package {
+    pub fn services(&self) -> Result<Vec<ServiceInfo>, provider::error::Error> {
+        service::services(self.backend, &self.providers.service)
+    }
+
+    pub fn daemon_reload(&self) -> Result<bool, provider::error::Error> {
+        service::daemon_reload(self.backend, &self.providers.service)
+    }
+
+    pub fn package(&self, name: &'static str, version: Option<&'static str>) -> Package<'_> {
         Package::new(name, version, self.backend, &self.providers.package)
     }
 
-    pub fn port(&self, number: usize) -> Port {
+    pub fn package_with(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        kind: PackageKind,
+    ) -> Package<'_> {
+        let provider = match kind {
+            PackageKind::Os => &self.providers.package,
+            PackageKind::Pip => &self.providers.pip_package,
+            PackageKind::Gem => &self.providers.gem_package,
+            PackageKind::Npm => &self.providers.npm_package,
+            PackageKind::Cargo => &self.providers.cargo_package,
+        };
+        Package::new(name, version, self.backend, provider)
+    }
+
+    pub fn packages(&self) -> Result<Vec<PackageInfo>, provider::error::Error> {
+        package::installed(self.backend, &self.providers.package)
+    }
+
+    pub fn upgradable(&self) -> Result<Vec<PackageInfo>, provider::error::Error> {
+        package::upgradable(self.backend, &self.providers.package)
+    }
+
+    pub fn port(&self, number: usize) -> Port<'_> {
         Port::new(number, self.backend, &self.providers.port)
     }
 }
@@ -1,6 +1,11 @@
 use crate::platform::base_platform::BasePlatform;
+use crate::platform::linux::alpine::Alpine;
+use crate::platform::linux::arch::Arch;
+use crate::platform::linux::gentoo::Gentoo;
 use crate::platform::linux::redhat::RedHat;
+use crate::platform::linux::suse::Suse;
 use crate::platform::linux::ubuntu::Ubuntu;
+use crate::platform::linux::void::Void;
 use crate::platform::platform::Platform;
 
 #[derive(Clone)]
@@ -14,6 +19,11 @@ impl BasePlatform for Linux {
         let mut p: Vec<Box<dyn Platform>> = Vec::new();
         p.push(Box::new(Ubuntu::new()));
         p.push(Box::new(RedHat::new()));
+        p.push(Box::new(Alpine::new()));
+        p.push(Box::new(Arch::new()));
+        p.push(Box::new(Suse::new()));
+        p.push(Box::new(Gentoo::new()));
+        p.push(Box::new(Void::new()));
 
         Linux {
             curr: 0,
@@ -39,5 +49,10 @@ impl Iterator for Linux {
     }
 }
 
+pub mod alpine;
+pub mod arch;
+pub mod gentoo;
 pub mod redhat;
+pub mod suse;
 pub mod ubuntu;
+pub mod void;
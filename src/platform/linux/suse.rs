@@ -0,0 +1,189 @@
+use crate::backend::Backend;
+use crate::platform::error::Error;
+use crate::platform::platform::Platform;
+use crate::provider::bond;
+use crate::provider::bond::BondProvider;
+use crate::provider::bridge;
+use crate::provider::bridge::BridgeProvider;
+use crate::provider::cgroup;
+use crate::provider::cgroup::CgroupProvider;
+use crate::provider::docker;
+use crate::provider::docker::DockerProvider;
+use crate::provider::file;
+use crate::provider::file::FileProvider;
+use crate::provider::host;
+use crate::provider::host::HostProvider;
+use crate::provider::linux_audit_system;
+use crate::provider::linux_audit_system::LinuxAuditSystemProvider;
+use crate::provider::package;
+use crate::provider::package::PackageProvider;
+use crate::provider::port;
+use crate::provider::port::PortProvider;
+use crate::provider::repository;
+use crate::provider::repository::RepositoryProvider;
+use crate::provider::service;
+use crate::provider::service::ServiceProvider;
+use crate::provider::Providers;
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Suse {
+    name: String,
+    release: String,
+}
+
+impl Platform for Suse {
+    fn new() -> Suse {
+        Suse {
+            name: "".to_string(),
+            release: "".to_string(),
+        }
+    }
+
+    fn inline_detector(&self) -> Option<Box<dyn Platform>> {
+        let mut file = match File::open("/etc/os-release") {
+            Err(_) => return None,
+            Ok(f) => f,
+        };
+
+        let mut contents = String::new();
+        let _ = file.read_to_string(&mut contents);
+        self.detect_by_os_release(&contents)
+    }
+
+    fn shell_detector(&self, b: &dyn Backend) -> Option<Box<dyn Platform>> {
+        let contents = match b.run_command("cat /etc/os-release".into()) {
+            Err(_) => return None,
+            Ok(f) => f,
+        };
+
+        self.detect_by_os_release(&contents.stdout)
+    }
+
+    fn get_providers(&self) -> Result<Box<Providers>, Error> {
+        let file_provider = FileProvider {
+            inline: Box::new(file::inline::posix::Posix),
+            shell: Box::new(file::shell::linux::Linux),
+        };
+
+        let service_provider = ServiceProvider {
+            inline: Box::new(service::inline::systemd::Systemd::default()),
+            shell: Box::new(service::shell::systemd::Systemd),
+        };
+
+        let package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::zypper::Zypper),
+        };
+
+        let port_provider = PortProvider {
+            inline: Box::new(port::inline::null::Null),
+            shell: Box::new(port::shell::netstat::Netstat),
+        };
+
+        let host_provider = HostProvider {
+            inline: Box::new(host::inline::posix::Posix),
+            shell: Box::new(host::shell::linux::Linux),
+        };
+
+        let docker_provider = DockerProvider {
+            inline: Box::new(docker::inline::engine::Engine),
+            shell: Box::new(docker::shell::docker::Docker),
+        };
+
+        let linux_audit_system_provider = LinuxAuditSystemProvider {
+            inline: Box::new(linux_audit_system::inline::null::Null),
+            shell: Box::new(linux_audit_system::shell::auditctl::Auditctl),
+        };
+
+        let cgroup_provider = CgroupProvider {
+            inline: Box::new(cgroup::inline::sysfs::Sysfs),
+            shell: Box::new(cgroup::shell::linux::Linux),
+        };
+
+        let bond_provider = BondProvider {
+            inline: Box::new(bond::inline::procfs::Procfs),
+            shell: Box::new(bond::shell::linux::Linux),
+        };
+
+        let bridge_provider = BridgeProvider {
+            inline: Box::new(bridge::inline::sysfs::Sysfs),
+            shell: Box::new(bridge::shell::linux::Linux),
+        };
+
+        let repository_provider = RepositoryProvider {
+            inline: Box::new(repository::inline::null::Null),
+            shell: Box::new(repository::shell::null::Null),
+        };
+
+        let pip_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::pip::Pip),
+        };
+
+        let gem_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::gem::Gem),
+        };
+
+        let npm_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::npm::Npm),
+        };
+
+        let cargo_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::cargo::Cargo),
+        };
+
+        let p = Providers {
+            file: Box::new(file_provider),
+            service: Box::new(service_provider),
+            package: Box::new(package_provider),
+            port: Box::new(port_provider),
+            host: Box::new(host_provider),
+            docker: Box::new(docker_provider),
+            linux_audit_system: Box::new(linux_audit_system_provider),
+            cgroup: Box::new(cgroup_provider),
+            bond: Box::new(bond_provider),
+            bridge: Box::new(bridge_provider),
+            repository: Box::new(repository_provider),
+            pip_package: Box::new(pip_package_provider),
+            gem_package: Box::new(gem_package_provider),
+            npm_package: Box::new(npm_package_provider),
+            cargo_package: Box::new(cargo_package_provider),
+        };
+
+        Ok(Box::new(p))
+    }
+}
+
+impl Suse {
+    fn detect_by_os_release(&self, contents: &str) -> Option<Box<dyn Platform>> {
+        let mut id = "";
+        let mut release = "";
+        for line in contents.lines() {
+            match line.split_once('=') {
+                Some(("ID", v)) => id = v.trim_matches('"'),
+                Some(("VERSION_ID", v)) => release = v.trim_matches('"'),
+                _ => (),
+            }
+        }
+
+        let name = match id {
+            "sles" | "sled" => "SUSE Linux Enterprise",
+            n if n.starts_with("opensuse") => "openSUSE",
+            _ => return None,
+        };
+
+        let s = Suse {
+            name: name.to_string(),
+            release: release.to_string(),
+        };
+
+        Some(Box::new(s))
+    }
+}
@@ -1,12 +1,26 @@
 use crate::backend::Backend;
 use crate::platform::error::Error;
 use crate::platform::platform::Platform;
+use crate::provider::bond;
+use crate::provider::bond::BondProvider;
+use crate::provider::bridge;
+use crate::provider::bridge::BridgeProvider;
+use crate::provider::cgroup;
+use crate::provider::cgroup::CgroupProvider;
+use crate::provider::docker;
+use crate::provider::docker::DockerProvider;
 use crate::provider::file;
 use crate::provider::file::FileProvider;
+use crate::provider::host;
+use crate::provider::host::HostProvider;
+use crate::provider::linux_audit_system;
+use crate::provider::linux_audit_system::LinuxAuditSystemProvider;
 use crate::provider::package;
 use crate::provider::package::PackageProvider;
 use crate::provider::port;
 use crate::provider::port::PortProvider;
+use crate::provider::repository;
+use crate::provider::repository::RepositoryProvider;
 use crate::provider::service;
 use crate::provider::service::ServiceProvider;
 use crate::provider::Providers;
@@ -59,7 +73,7 @@ impl Platform for Ubuntu {
 
         let service_provider = match r {
             n if n >= 16.0 => ServiceProvider {
-                inline: Box::new(service::inline::systemd::Systemd),
+                inline: Box::new(service::inline::systemd::Systemd::default()),
                 shell: Box::new(service::shell::systemd::Systemd),
             },
             _ => ServiceProvider {
@@ -69,7 +83,7 @@ impl Platform for Ubuntu {
         };
 
         let package_provider = PackageProvider {
-            inline: Box::new(package::inline::null::Null),
+            inline: Box::new(package::inline::dpkg::Dpkg),
             shell: Box::new(package::shell::apt::Apt),
         };
 
@@ -78,11 +92,77 @@ impl Platform for Ubuntu {
             shell: Box::new(port::shell::netstat::Netstat),
         };
 
+        let host_provider = HostProvider {
+            inline: Box::new(host::inline::posix::Posix),
+            shell: Box::new(host::shell::linux::Linux),
+        };
+
+        let docker_provider = DockerProvider {
+            inline: Box::new(docker::inline::engine::Engine),
+            shell: Box::new(docker::shell::docker::Docker),
+        };
+
+        let linux_audit_system_provider = LinuxAuditSystemProvider {
+            inline: Box::new(linux_audit_system::inline::null::Null),
+            shell: Box::new(linux_audit_system::shell::auditctl::Auditctl),
+        };
+
+        let cgroup_provider = CgroupProvider {
+            inline: Box::new(cgroup::inline::sysfs::Sysfs),
+            shell: Box::new(cgroup::shell::linux::Linux),
+        };
+
+        let bond_provider = BondProvider {
+            inline: Box::new(bond::inline::procfs::Procfs),
+            shell: Box::new(bond::shell::linux::Linux),
+        };
+
+        let bridge_provider = BridgeProvider {
+            inline: Box::new(bridge::inline::sysfs::Sysfs),
+            shell: Box::new(bridge::shell::linux::Linux),
+        };
+
+        let repository_provider = RepositoryProvider {
+            inline: Box::new(repository::inline::apt::Apt),
+            shell: Box::new(repository::shell::apt::Apt),
+        };
+
+        let pip_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::pip::Pip),
+        };
+
+        let gem_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::gem::Gem),
+        };
+
+        let npm_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::npm::Npm),
+        };
+
+        let cargo_package_provider = PackageProvider {
+            inline: Box::new(package::inline::null::Null),
+            shell: Box::new(package::shell::cargo::Cargo),
+        };
+
         let p = Providers {
             file: Box::new(file_provider),
             service: Box::new(service_provider),
             package: Box::new(package_provider),
             port: Box::new(port_provider),
+            host: Box::new(host_provider),
+            docker: Box::new(docker_provider),
+            linux_audit_system: Box::new(linux_audit_system_provider),
+            cgroup: Box::new(cgroup_provider),
+            bond: Box::new(bond_provider),
+            bridge: Box::new(bridge_provider),
+            repository: Box::new(repository_provider),
+            pip_package: Box::new(pip_package_provider),
+            gem_package: Box::new(gem_package_provider),
+            npm_package: Box::new(npm_package_provider),
+            cargo_package: Box::new(cargo_package_provider),
         };
 
         Ok(Box::new(p))
@@ -0,0 +1,58 @@
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait InlineProvider: Debug {
+    fn exists(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn mode(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "mode".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn slaves(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "slaves".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn active_slave(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "active_slave".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn miimon(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "miimon".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider>;
+}
+
+impl Clone for Box<dyn InlineProvider> {
+    fn clone(&self) -> Box<dyn InlineProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod null;
+pub mod procfs;
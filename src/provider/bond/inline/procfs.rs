@@ -0,0 +1,51 @@
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::bond;
+use crate::provider::bond::inline::InlineProvider;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Procfs;
+
+impl Procfs {
+    fn bonding(&self, name: &str) -> Result<String, Error> {
+        let contents = fs::read_to_string(bond::bonding_path(name))?;
+        Ok(contents)
+    }
+}
+
+impl InlineProvider for Procfs {
+    fn exists(&self, name: &str) -> Result<Output, Error> {
+        let res = Output::Bool(Path::new(&bond::bonding_path(name)).exists());
+        Ok(res)
+    }
+
+    fn mode(&self, name: &str) -> Result<Output, Error> {
+        let contents = self.bonding(name)?;
+        let mode = bond::bonding_field(&contents, "Bonding Mode").unwrap_or_default();
+        Ok(Output::Text(mode))
+    }
+
+    fn slaves(&self, name: &str) -> Result<Output, Error> {
+        let contents = self.bonding(name)?;
+        Ok(Output::List(bond::bonding_slaves(&contents)))
+    }
+
+    fn active_slave(&self, name: &str) -> Result<Output, Error> {
+        let contents = self.bonding(name)?;
+        let slave = bond::bonding_field(&contents, "Currently Active Slave").unwrap_or_default();
+        Ok(Output::Text(slave))
+    }
+
+    fn miimon(&self, name: &str) -> Result<Output, Error> {
+        let contents = self.bonding(name)?;
+        Ok(Output::U32(bond::bonding_miimon(&contents)?))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
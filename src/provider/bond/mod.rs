@@ -0,0 +1,98 @@
+use crate::provider::bond::inline::InlineProvider;
+use crate::provider::bond::shell::ShellProvider;
+use crate::provider::error::Error;
+use crate::provider::error::StringError;
+use crate::provider::HandleFunc;
+
+pub struct BondProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl BondProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> BondProvider {
+        BondProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn exists(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.exists(name)),
+            shell: Box::new(move |b| s.exists(name, b)),
+        })
+    }
+
+    pub fn mode(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.mode(name)),
+            shell: Box::new(move |b| s.mode(name, b)),
+        })
+    }
+
+    pub fn slaves(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.slaves(name)),
+            shell: Box::new(move |b| s.slaves(name, b)),
+        })
+    }
+
+    pub fn active_slave(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.active_slave(name)),
+            shell: Box::new(move |b| s.active_slave(name, b)),
+        })
+    }
+
+    pub fn miimon(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.miimon(name)),
+            shell: Box::new(move |b| s.miimon(name, b)),
+        })
+    }
+}
+
+pub fn bonding_path(name: &str) -> String {
+    format!("/proc/net/bonding/{}", name)
+}
+
+// Value of the first "key: value" line of a /proc/net/bonding file.
+pub fn bonding_field(contents: &str, key: &str) -> Option<String> {
+    contents
+        .lines()
+        .find_map(|line| match line.split_once(':') {
+            Some((k, v)) if k.trim() == key => Some(v.trim().to_string()),
+            _ => None,
+        })
+}
+
+pub fn bonding_slaves(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .filter_map(|line| match line.split_once(':') {
+            Some((k, v)) if k.trim() == "Slave Interface" => Some(v.trim().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn bonding_miimon(contents: &str) -> Result<u32, Error> {
+    let miimon = bonding_field(contents, "MII Polling Interval (ms)").ok_or(StringError {
+        string: "MII Polling Interval not found".to_string(),
+    })?;
+    Ok(miimon.parse::<u32>()?)
+}
+
+pub mod inline;
+pub mod shell;
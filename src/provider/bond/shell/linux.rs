@@ -0,0 +1,56 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::bond;
+use crate::provider::bond::shell::ShellProvider;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Linux;
+
+impl Linux {
+    fn bonding(&self, name: &str, b: &dyn Backend) -> Result<String, Error> {
+        let c = Command::new(&format!("cat {}", bond::bonding_path(name)));
+        let res = b.run_command(c)?;
+        Ok(res.stdout)
+    }
+}
+
+impl ShellProvider for Linux {
+    fn exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("test -f {}", bond::bonding_path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn mode(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let contents = self.bonding(name, b)?;
+        let mode = bond::bonding_field(&contents, "Bonding Mode").unwrap_or_default();
+        Ok(Output::Text(mode))
+    }
+
+    fn slaves(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let contents = self.bonding(name, b)?;
+        Ok(Output::List(bond::bonding_slaves(&contents)))
+    }
+
+    fn active_slave(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let contents = self.bonding(name, b)?;
+        let slave = bond::bonding_field(&contents, "Currently Active Slave").unwrap_or_default();
+        Ok(Output::Text(slave))
+    }
+
+    fn miimon(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let contents = self.bonding(name, b)?;
+        Ok(Output::U32(bond::bonding_miimon(&contents)?))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
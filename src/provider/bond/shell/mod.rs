@@ -0,0 +1,59 @@
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait ShellProvider: Debug {
+    fn exists(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn mode(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "mode".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn slaves(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "slaves".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn active_slave(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "active_slave".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn miimon(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "miimon".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider>;
+}
+
+impl Clone for Box<dyn ShellProvider> {
+    fn clone(&self) -> Box<dyn ShellProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod linux;
+pub mod null;
@@ -0,0 +1,42 @@
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait InlineProvider: Debug {
+    fn exists(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn interfaces(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "interfaces".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn stp_enabled(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "stp_enabled".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider>;
+}
+
+impl Clone for Box<dyn InlineProvider> {
+    fn clone(&self) -> Box<dyn InlineProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod null;
+pub mod sysfs;
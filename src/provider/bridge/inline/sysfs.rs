@@ -0,0 +1,35 @@
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::bridge::inline::InlineProvider;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Sysfs;
+
+impl InlineProvider for Sysfs {
+    fn exists(&self, name: &str) -> Result<Output, Error> {
+        let path = format!("/sys/class/net/{}/bridge", name);
+        Ok(Output::Bool(Path::new(&path).is_dir()))
+    }
+
+    fn interfaces(&self, name: &str) -> Result<Output, Error> {
+        let mut interfaces = Vec::new();
+        for entry in fs::read_dir(format!("/sys/class/net/{}/brif", name))? {
+            interfaces.push(entry?.file_name().to_string_lossy().into_owned());
+        }
+        interfaces.sort();
+        Ok(Output::List(interfaces))
+    }
+
+    fn stp_enabled(&self, name: &str) -> Result<Output, Error> {
+        let state = fs::read_to_string(format!("/sys/class/net/{}/bridge/stp_state", name))?;
+        Ok(Output::Bool(state.trim() != "0"))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::provider::bridge::inline::InlineProvider;
+use crate::provider::bridge::shell::ShellProvider;
+use crate::provider::HandleFunc;
+
+pub struct BridgeProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl BridgeProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> BridgeProvider {
+        BridgeProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn exists(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.exists(name)),
+            shell: Box::new(move |b| s.exists(name, b)),
+        })
+    }
+
+    pub fn interfaces(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.interfaces(name)),
+            shell: Box::new(move |b| s.interfaces(name, b)),
+        })
+    }
+
+    pub fn stp_enabled(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.stp_enabled(name)),
+            shell: Box::new(move |b| s.stp_enabled(name, b)),
+        })
+    }
+}
+
+pub mod inline;
+pub mod shell;
@@ -0,0 +1,39 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::bridge::shell::ShellProvider;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Linux;
+
+impl ShellProvider for Linux {
+    fn exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("test -d /sys/class/net/{}/bridge", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn interfaces(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("ls -1 /sys/class/net/{}/brif", name));
+        let res = b.run_command(c)?;
+        let mut interfaces: Vec<String> = res.stdout.lines().map(|l| l.to_string()).collect();
+        interfaces.sort();
+        Ok(Output::List(interfaces))
+    }
+
+    fn stp_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("cat /sys/class/net/{}/bridge/stp_state", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.stdout != "0"))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
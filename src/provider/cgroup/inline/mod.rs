@@ -0,0 +1,26 @@
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait InlineProvider: Debug {
+    fn value(&self, _: &str, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "value".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider>;
+}
+
+impl Clone for Box<dyn InlineProvider> {
+    fn clone(&self) -> Box<dyn InlineProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod null;
+pub mod sysfs;
@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::cgroup;
+use crate::provider::cgroup::inline::InlineProvider;
+use crate::provider::cgroup::CGROUP_ROOT;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Sysfs;
+
+impl InlineProvider for Sysfs {
+    fn value(&self, name: &str, key: &str) -> Result<Output, Error> {
+        let unified = Path::new(CGROUP_ROOT).join("cgroup.controllers").exists();
+
+        let path = if unified {
+            cgroup::unified_path(name, key)
+        } else {
+            let legacy = cgroup::legacy_path(name, key);
+            if Path::new(&legacy).exists() {
+                legacy
+            } else {
+                cgroup::hybrid_path(name, key)
+            }
+        };
+
+        let value = fs::read_to_string(path)?;
+        Ok(Output::Text(value.trim().to_string()))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::provider::cgroup::inline::InlineProvider;
+use crate::provider::cgroup::shell::ShellProvider;
+use crate::provider::HandleFunc;
+
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+pub struct CgroupProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl CgroupProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> CgroupProvider {
+        CgroupProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn value(&self, name: &'static str, key: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.value(name, key)),
+            shell: Box::new(move |b| s.value(name, key, b)),
+        })
+    }
+}
+
+// Path of key for cgroup name when the v2 unified hierarchy is mounted on
+// the cgroup root.
+pub fn unified_path(name: &str, key: &str) -> String {
+    format!("{}/{}/{}", CGROUP_ROOT, name.trim_matches('/'), key)
+}
+
+// Path of key for cgroup name in the v1 hierarchy of the controller the key
+// belongs to, e.g. memory for memory.limit_in_bytes.
+pub fn legacy_path(name: &str, key: &str) -> String {
+    let controller = key.split('.').next().unwrap_or(key);
+    format!(
+        "{}/{}/{}/{}",
+        CGROUP_ROOT,
+        controller,
+        name.trim_matches('/'),
+        key
+    )
+}
+
+// Path of key for cgroup name in the unified hierarchy of a hybrid setup,
+// which mounts it next to the v1 hierarchies.
+pub fn hybrid_path(name: &str, key: &str) -> String {
+    format!("{}/unified/{}/{}", CGROUP_ROOT, name.trim_matches('/'), key)
+}
+
+pub mod inline;
+pub mod shell;
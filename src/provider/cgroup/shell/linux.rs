@@ -0,0 +1,32 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::cgroup;
+use crate::provider::cgroup::shell::ShellProvider;
+use crate::provider::cgroup::CGROUP_ROOT;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Linux;
+
+impl ShellProvider for Linux {
+    fn value(&self, name: &str, key: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let legacy = cgroup::legacy_path(name, key);
+        let c = Command::new(&format!(
+            "if test -f {}/cgroup.controllers; then cat {}; elif test -e {}; then cat {}; else cat {}; fi",
+            CGROUP_ROOT,
+            cgroup::unified_path(name, key),
+            legacy,
+            legacy,
+            cgroup::hybrid_path(name, key)
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Text(res.stdout))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait ShellProvider: Debug {
+    fn value(&self, _: &str, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "value".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider>;
+}
+
+impl Clone for Box<dyn ShellProvider> {
+    fn clone(&self) -> Box<dyn ShellProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod linux;
+pub mod null;
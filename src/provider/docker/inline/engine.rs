@@ -0,0 +1,90 @@
+use std::io::prelude::*;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::docker::inline::InlineProvider;
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::error::StringError;
+use crate::provider::Output;
+
+const DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
+#[derive(Clone, Debug)]
+pub struct Engine;
+
+impl Engine {
+    fn get(&self, func: &str, path: &str) -> Result<(u32, String), Error> {
+        // Without a local engine socket the docker CLI may still know how to
+        // reach the engine (e.g. through DOCKER_HOST), so defer to the shell
+        // provider.
+        if !Path::new(DOCKER_SOCKET).exists() {
+            let e = HandleFuncNotDefined {
+                provider: format!("{:?}", self),
+                func: func.to_string(),
+            };
+            return Err(e.into());
+        }
+
+        let mut stream = UnixStream::connect(DOCKER_SOCKET)?;
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: docker\r\nConnection: close\r\n\r\n",
+            path
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        let malformed = || StringError {
+            string: "Malformed response from docker engine".to_string(),
+        };
+        let (head, body) = response.split_once("\r\n\r\n").ok_or_else(malformed)?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(malformed)?
+            .parse::<u32>()?;
+
+        Ok((status, body.to_string()))
+    }
+
+    fn exists(&self, func: &str, path: &str) -> Result<Output, Error> {
+        let (status, _) = self.get(func, path)?;
+        Ok(Output::Bool(status == 200))
+    }
+
+    fn inspect(&self, func: &str, path: &str) -> Result<Output, Error> {
+        let (status, body) = self.get(func, path)?;
+        if status != 200 {
+            let e = StringError {
+                string: format!("Docker engine returned {}: {}", status, body.trim()),
+            };
+            return Err(e.into());
+        }
+        Ok(Output::Text(body))
+    }
+}
+
+impl InlineProvider for Engine {
+    fn container_exists(&self, name: &str) -> Result<Output, Error> {
+        self.exists("container_exists", &format!("/containers/{}/json", name))
+    }
+
+    fn inspect_container(&self, name: &str) -> Result<Output, Error> {
+        self.inspect("inspect_container", &format!("/containers/{}/json", name))
+    }
+
+    fn image_exists(&self, name: &str) -> Result<Output, Error> {
+        self.exists("image_exists", &format!("/images/{}/json", name))
+    }
+
+    fn inspect_image(&self, name: &str) -> Result<Output, Error> {
+        self.inspect("inspect_image", &format!("/images/{}/json", name))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
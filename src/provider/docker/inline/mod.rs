@@ -0,0 +1,50 @@
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait InlineProvider: Debug {
+    fn container_exists(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "container_exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn inspect_container(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "inspect_container".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn image_exists(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "image_exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn inspect_image(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "inspect_image".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider>;
+}
+
+impl Clone for Box<dyn InlineProvider> {
+    fn clone(&self) -> Box<dyn InlineProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod engine;
+pub mod null;
@@ -0,0 +1,97 @@
+use crate::provider::docker::inline::InlineProvider;
+use crate::provider::docker::shell::ShellProvider;
+use crate::provider::error::Error;
+use crate::provider::error::StringError;
+use crate::provider::HandleFunc;
+
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+pub struct DockerProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl DockerProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> DockerProvider {
+        DockerProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn container_exists(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.container_exists(name)),
+            shell: Box::new(move |b| s.container_exists(name, b)),
+        })
+    }
+
+    pub fn inspect_container(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.inspect_container(name)),
+            shell: Box::new(move |b| s.inspect_container(name, b)),
+        })
+    }
+
+    pub fn image_exists(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.image_exists(name)),
+            shell: Box::new(move |b| s.image_exists(name, b)),
+        })
+    }
+
+    pub fn inspect_image(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.inspect_image(name)),
+            shell: Box::new(move |b| s.inspect_image(name, b)),
+        })
+    }
+}
+
+// Both providers hand back the inspect document of the engine API as JSON
+// text, so the resources share these helpers to pick fields out of it.
+
+pub fn parse_inspect(s: &str) -> Result<Value, Error> {
+    let v: Value = serde_json::from_str(s)?;
+    Ok(v)
+}
+
+pub fn inspect_field<'a>(v: &'a Value, pointer: &str) -> Result<&'a Value, Error> {
+    v.pointer(pointer).ok_or_else(|| {
+        StringError {
+            string: format!("{} not found in inspect output", pointer),
+        }
+        .into()
+    })
+}
+
+pub fn inspect_string(v: &Value, pointer: &str) -> Result<String, Error> {
+    let field = inspect_field(v, pointer)?;
+    let s = field.as_str().ok_or(StringError {
+        string: format!("{} is not a string", pointer),
+    })?;
+    Ok(s.to_string())
+}
+
+pub fn inspect_labels(v: &Value) -> HashMap<String, String> {
+    let mut labels = HashMap::new();
+    if let Some(m) = v.pointer("/Config/Labels").and_then(Value::as_object) {
+        for (key, value) in m {
+            labels.insert(key.clone(), value.as_str().unwrap_or("").to_string());
+        }
+    }
+    labels
+}
+
+pub mod inline;
+pub mod shell;
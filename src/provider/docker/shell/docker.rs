@@ -0,0 +1,55 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::docker::shell::ShellProvider;
+use crate::provider::error::Error;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Docker;
+
+impl ShellProvider for Docker {
+    fn container_exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.exists("container", name, b)
+    }
+
+    fn inspect_container(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.inspect("container", name, b)
+    }
+
+    fn image_exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.exists("image", name, b)
+    }
+
+    fn inspect_image(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.inspect("image", name, b)
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+impl Docker {
+    fn exists(&self, kind: &str, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "docker inspect --type {} --format '{{{{.Id}}}}' {}",
+            kind, name
+        ));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn inspect(&self, kind: &str, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "docker inspect --type {} --format '{{{{json .}}}}' {}",
+            kind, name
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Text(res.stdout))
+    }
+}
@@ -0,0 +1,51 @@
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait ShellProvider: Debug {
+    fn container_exists(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "container_exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn inspect_container(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "inspect_container".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn image_exists(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "image_exists".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn inspect_image(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "inspect_image".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider>;
+}
+
+impl Clone for Box<dyn ShellProvider> {
+    fn clone(&self) -> Box<dyn ShellProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod docker;
+pub mod null;
@@ -16,6 +16,8 @@ pub enum Error {
     Io(io::Error),
     String(StringError),
     ParseInt(num::ParseIntError),
+    Json(serde_json::Error),
+    Sqlite(rusqlite::Error),
     Output(OutputError),
     Backend(backend::error::Error),
     Service(service::error::Error),
@@ -29,6 +31,8 @@ impl fmt::Display for Error {
             Error::Io(ref err) => err.fmt(f),
             Error::String(ref err) => err.fmt(f),
             Error::ParseInt(ref err) => err.fmt(f),
+            Error::Json(ref err) => err.fmt(f),
+            Error::Sqlite(ref err) => err.fmt(f),
             Error::Output(ref err) => err.fmt(f),
             Error::Backend(ref err) => err.fmt(f),
             Error::Service(ref err) => err.fmt(f),
@@ -54,6 +58,18 @@ impl From<num::ParseIntError> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Json(err)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Error {
+        Error::Sqlite(err)
+    }
+}
+
 impl From<OutputError> for Error {
     fn from(err: OutputError) -> Error {
         Error::Output(err)
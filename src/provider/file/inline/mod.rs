@@ -160,6 +160,30 @@ pub trait InlineProvider: Debug {
         Err(e.into())
     }
 
+    fn write_contents(&self, _: &str, _: &[u8]) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "write_contents".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn append(&self, _: &str, _: &[u8]) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "append".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn replace(&self, _: &str, _: &[u8]) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "replace".to_string(),
+        };
+        Err(e.into())
+    }
+
     fn box_clone(&self) -> Box<dyn InlineProvider>;
 }
 
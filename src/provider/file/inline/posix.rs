@@ -1,10 +1,15 @@
+use std::collections::hash_map::RandomState;
 use std::fs;
+use std::hash::BuildHasher;
+use std::io;
 use std::io::prelude::*;
 use std::io::BufReader;
-use std::os::unix::fs::{FileTypeExt, MetadataExt, PermissionsExt};
+use std::os::unix::fs::{fchown, FileTypeExt, MetadataExt, PermissionsExt};
 use std::os::unix::io::{AsRawFd, RawFd};
 use std::path;
+use std::process;
 use std::result::Result;
+use std::time::SystemTime;
 
 use crate::provider::error::Error;
 use crate::provider::error::StringError;
@@ -19,6 +24,9 @@ use sha2::{self, Digest, Sha256};
 
 use nix;
 
+// How many temporary file names `replace` tries before giving up.
+const TEMP_ATTEMPTS: u32 = 100;
+
 #[derive(Clone, Debug)]
 pub struct Posix;
 
@@ -48,6 +56,54 @@ impl Posix {
         Ok(contents)
     }
 
+    // Creates a new file next to `target`, so that renaming it over `target`
+    // does not cross filesystems. Like mktemp, the name ends in a random
+    // suffix and is retried if another file already has it.
+    fn create_temp(&self, target: &path::Path) -> Result<(path::PathBuf, fs::File), Error> {
+        let file_name = target
+            .file_name()
+            .and_then(|f| f.to_str())
+            .ok_or(StringError {
+                string: format!("Invalid file name: {}", target.display()),
+            })?;
+
+        let state = RandomState::new();
+        for attempt in 0..TEMP_ATTEMPTS {
+            let suffix = state.hash_one((process::id(), SystemTime::now(), attempt));
+            let tmp = target.with_file_name(format!(".{}.{:016x}.tmp", file_name, suffix));
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&tmp)
+            {
+                Ok(file) => return Ok((tmp, file)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err(StringError {
+            string: format!("Failed to create a temporary file for {}", target.display()),
+        }
+        .into())
+    }
+
+    fn write_temp(
+        &self,
+        target: &path::Path,
+        mut file: fs::File,
+        contents: &[u8],
+    ) -> Result<(), Error> {
+        file.write_all(contents)?;
+        if let Ok(m) = fs::metadata(target) {
+            // chown clears the setuid and setgid bits, so it goes first.
+            fchown(&file, Some(m.uid()), Some(m.gid()))?;
+            file.set_permissions(m.permissions())?;
+        }
+        file.sync_all()?;
+        Ok(())
+    }
+
     // FIXME: is_readableとis_writableをまとめる
     fn is_readable_by_user(&self, name: &str, user: &str) -> Result<Output, Error> {
         let file_owner = self.file_owner(name)?;
@@ -209,6 +265,34 @@ impl InlineProvider for Posix {
         Ok(Output::Text(format!("{:x}", output)))
     }
 
+    fn write_contents(&self, name: &str, contents: &[u8]) -> Result<Output, Error> {
+        fs::write(name, contents)?;
+        Ok(Output::Bool(true))
+    }
+
+    fn append(&self, name: &str, contents: &[u8]) -> Result<Output, Error> {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(name)?;
+        file.write_all(contents)?;
+        Ok(Output::Bool(true))
+    }
+
+    // A symbolic link at `name` is kept and the file it points to is
+    // replaced. A dangling link is replaced by a regular file.
+    fn replace(&self, name: &str, contents: &[u8]) -> Result<Output, Error> {
+        let target = fs::canonicalize(name).unwrap_or_else(|_| path::PathBuf::from(name));
+        let (tmp, file) = self.create_temp(&target)?;
+        let res = self
+            .write_temp(&target, file, contents)
+            .and_then(|_| fs::rename(&tmp, &target).map_err(Error::from));
+        if res.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        res.map(|_| Output::Bool(true))
+    }
+
     fn selinux_label(&self, _: &str) -> Result<Output, Error> {
         unimplemented!()
     }
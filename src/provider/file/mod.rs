@@ -112,12 +112,47 @@ impl FileProvider {
         })
     }
 
-    pub fn contents(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn contents(&self, name: &str) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
+        let inline_name = name.to_string();
+        let shell_name = name.to_string();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.contents(name)),
-            shell: Box::new(move |b| s.contents(name, b)),
+            inline: Box::new(move || i.contents(&inline_name)),
+            shell: Box::new(move |b| s.contents(&shell_name, b)),
+        })
+    }
+
+    pub fn write_contents(&self, name: &'static str, contents: &[u8]) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        let inline_contents = contents.to_vec();
+        let shell_contents = contents.to_vec();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.write_contents(name, &inline_contents)),
+            shell: Box::new(move |b| s.write_contents(name, &shell_contents, b)),
+        })
+    }
+
+    pub fn append(&self, name: &'static str, contents: &[u8]) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        let inline_contents = contents.to_vec();
+        let shell_contents = contents.to_vec();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.append(name, &inline_contents)),
+            shell: Box::new(move |b| s.append(name, &shell_contents, b)),
+        })
+    }
+
+    pub fn replace(&self, name: &'static str, contents: &[u8]) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        let inline_contents = contents.to_vec();
+        let shell_contents = contents.to_vec();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.replace(name, &inline_contents)),
+            shell: Box::new(move |b| s.replace(name, &shell_contents, b)),
         })
     }
 
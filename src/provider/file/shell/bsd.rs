@@ -55,6 +55,24 @@ impl ShellProvider for Bsd {
         Unix.contents(name, b)
     }
 
+    fn write_contents(
+        &self,
+        name: &str,
+        contents: &[u8],
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        Unix.write_contents(name, contents, b)
+    }
+
+    fn append(&self, name: &str, contents: &[u8], b: &dyn Backend) -> Result<Output, Error> {
+        Unix.append(name, contents, b)
+    }
+
+    fn replace(&self, name: &str, contents: &[u8], b: &dyn Backend) -> Result<Output, Error> {
+        let keep = "chown $(stat -f%u:%g \"$f\") \"$t\" && chmod $(stat -f%Mp%Lp \"$f\") \"$t\"";
+        Unix.replace_keeping(name, keep, contents, b)
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider> {
         Box::new((*self).clone())
     }
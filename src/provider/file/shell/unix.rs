@@ -61,6 +61,25 @@ impl ShellProvider for Unix {
         Ok(Output::Text(res.stdout))
     }
 
+    fn write_contents(
+        &self,
+        name: &str,
+        contents: &[u8],
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let mut c = Command::new(&format!("cat > {}", name));
+        c.stdin(contents);
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn append(&self, name: &str, contents: &[u8], b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new(&format!("cat >> {}", name));
+        c.stdin(contents);
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
     fn linked_to(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
         let c = Command::new(&format!("readlink {}", name));
         let res = b.run_command(c)?;
@@ -89,4 +108,31 @@ impl Unix {
         };
         Ok(Output::Bool(success))
     }
+
+    // Writes `contents` to a temporary file next to `name` and moves it into
+    // place. An existing `name` is resolved into "$f" first, so a symbolic
+    // link is kept and the file it points to is replaced. `keep` copies the
+    // owner and then the mode of "$f" onto the temporary file "$t", since
+    // chown clears the setuid and setgid bits; a new file gets the mode the
+    // umask allows.
+    pub fn replace_keeping(
+        &self,
+        name: &str,
+        keep: &str,
+        contents: &[u8],
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let mut c = Command::new(&format!(
+            "if test -e {name}; then f=$(readlink -f {name}) || exit 1; else f={name}; fi && \
+             t=$(mktemp \"$(dirname \"$f\")/.$(basename \"$f\").XXXXXX\") && cat > \"$t\" && \
+             if test -e \"$f\"; then {keep}; \
+             else chmod $(printf %o $((0666 & ~$(umask)))) \"$t\"; fi && \
+             mv -f \"$t\" \"$f\" || {{ rm -f \"$t\"; exit 1; }}",
+            name = name,
+            keep = keep,
+        ));
+        c.stdin(contents);
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
 }
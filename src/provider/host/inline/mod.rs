@@ -0,0 +1,49 @@
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::host::{Protocol, Resolver};
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait InlineProvider: Debug {
+    fn is_resolvable(&self, _: &str, _: Resolver) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_resolvable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn ipaddress(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "ipaddress".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_reachable(
+        &self,
+        _: &str,
+        _: Option<usize>,
+        _: Protocol,
+        _: u64,
+    ) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_reachable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider>;
+}
+
+impl Clone for Box<dyn InlineProvider> {
+    fn clone(&self) -> Box<dyn InlineProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod null;
+pub mod posix;
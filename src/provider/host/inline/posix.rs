@@ -0,0 +1,99 @@
+use std::fs;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::result::Result;
+use std::time::Duration;
+
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::error::StringError;
+use crate::provider::host::inline::InlineProvider;
+use crate::provider::host::{Protocol, Resolver};
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Posix;
+
+impl Posix {
+    fn lookup(&self, name: &str, port: u16) -> Vec<SocketAddr> {
+        match (name, port).to_socket_addrs() {
+            Ok(addrs) => addrs.collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn is_in_hosts_file(&self, name: &str) -> Result<bool, Error> {
+        let contents = fs::read_to_string("/etc/hosts")?;
+        let found = contents.lines().any(|line| {
+            let line = line.split('#').next().unwrap_or("");
+            line.split_whitespace()
+                .skip(1)
+                .any(|h| h.eq_ignore_ascii_case(name))
+        });
+        Ok(found)
+    }
+}
+
+impl InlineProvider for Posix {
+    fn is_resolvable(&self, name: &str, by: Resolver) -> Result<Output, Error> {
+        match by {
+            Resolver::Any => Ok(Output::Bool(!self.lookup(name, 0).is_empty())),
+            Resolver::Hosts => Ok(Output::Bool(self.is_in_hosts_file(name)?)),
+            // The resolver library gives no way to restrict a lookup to DNS,
+            // so leave it to the shell provider.
+            Resolver::Dns => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "is_resolvable".to_string(),
+                };
+                Err(e.into())
+            }
+        }
+    }
+
+    fn ipaddress(&self, name: &str) -> Result<Output, Error> {
+        let addr = self.lookup(name, 0).into_iter().next().ok_or(StringError {
+            string: format!("Failed to resolve {}", name),
+        })?;
+        Ok(Output::Text(addr.ip().to_string()))
+    }
+
+    fn is_reachable(
+        &self,
+        name: &str,
+        port: Option<usize>,
+        proto: Protocol,
+        timeout: u64,
+    ) -> Result<Output, Error> {
+        let port = match (port, proto) {
+            (Some(p), Protocol::Tcp) => p,
+            _ => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "is_reachable".to_string(),
+                };
+                return Err(e.into());
+            }
+        };
+
+        let port = match port {
+            p if p <= u16::MAX as usize => p as u16,
+            p => {
+                let e = StringError {
+                    string: format!("Invalid port number: {}", p),
+                };
+                return Err(e.into());
+            }
+        };
+
+        let timeout = Duration::from_secs(timeout);
+        let reachable = self
+            .lookup(name, port)
+            .iter()
+            .any(|addr| TcpStream::connect_timeout(addr, timeout).is_ok());
+        Ok(Output::Bool(reachable))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::provider::host::inline::InlineProvider;
+use crate::provider::host::shell::ShellProvider;
+use crate::provider::HandleFunc;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Resolver {
+    Hosts,
+    Dns,
+    Any,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+pub struct HostProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl HostProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> HostProvider {
+        HostProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn is_resolvable(&self, name: &'static str, by: Resolver) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_resolvable(name, by)),
+            shell: Box::new(move |b| s.is_resolvable(name, by, b)),
+        })
+    }
+
+    pub fn ipaddress(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.ipaddress(name)),
+            shell: Box::new(move |b| s.ipaddress(name, b)),
+        })
+    }
+
+    pub fn is_reachable(
+        &self,
+        name: &'static str,
+        port: Option<usize>,
+        proto: Protocol,
+        timeout: u64,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_reachable(name, port, proto, timeout)),
+            shell: Box::new(move |b| s.is_reachable(name, port, proto, timeout, b)),
+        })
+    }
+}
+
+pub mod inline;
+pub mod shell;
@@ -0,0 +1,76 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::StringError;
+use crate::provider::host::shell::ShellProvider;
+use crate::provider::host::{Protocol, Resolver};
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Linux;
+
+impl ShellProvider for Linux {
+    fn is_resolvable(&self, name: &str, by: Resolver, b: &dyn Backend) -> Result<Output, Error> {
+        let c = match by {
+            Resolver::Hosts => Command::new(&format!("getent -s files ahosts {}", name)),
+            Resolver::Dns => Command::new(&format!("getent -s dns ahosts {}", name)),
+            Resolver::Any => Command::new(&format!("getent ahosts {}", name)),
+        };
+
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn ipaddress(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new(&format!("getent ahosts {}", name));
+        c.pipe("awk 'NR == 1 {print $1}'");
+        let res = b.run_command(c)?;
+        // The pipeline succeeds even when getent fails, leaving no output.
+        if res.stdout.is_empty() {
+            let e = StringError {
+                string: format!("Failed to resolve {}", name),
+            };
+            return Err(e.into());
+        }
+        Ok(Output::Text(res.stdout))
+    }
+
+    fn is_reachable(
+        &self,
+        name: &str,
+        port: Option<usize>,
+        proto: Protocol,
+        timeout: u64,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let c = match (port, proto) {
+            (None, _) => Command::new(&format!("ping -w {} -c 2 -n {}", timeout, name)),
+            (Some(p), Protocol::Tcp) => {
+                let mut c = Command::new(&format!("nc -z -w {} {} {}", timeout, name, p));
+                c.or(&format!(
+                    "timeout {} bash -c 'cat < /dev/null > /dev/tcp/{}/{}'",
+                    timeout, name, p
+                ));
+                c
+            }
+            (Some(p), Protocol::Udp) => {
+                Command::new(&format!("nc -z -u -w {} {} {}", timeout, name, p))
+            }
+        };
+
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
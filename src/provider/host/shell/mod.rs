@@ -0,0 +1,51 @@
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::host::{Protocol, Resolver};
+use crate::provider::Output;
+
+use std::fmt::Debug;
+
+pub trait ShellProvider: Debug {
+    fn is_resolvable(&self, _: &str, _: Resolver, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_resolvable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn ipaddress(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "ipaddress".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_reachable(
+        &self,
+        _: &str,
+        _: Option<usize>,
+        _: Protocol,
+        _: u64,
+        _: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_reachable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider>;
+}
+
+impl Clone for Box<dyn ShellProvider> {
+    fn clone(&self) -> Box<dyn ShellProvider> {
+        self.box_clone()
+    }
+}
+
+pub mod linux;
+pub mod null;
@@ -0,0 +1,47 @@
+use crate::provider::linux_audit_system::inline::InlineProvider;
+use crate::provider::linux_audit_system::shell::ShellProvider;
+use crate::provider::HandleFunc;
+
+pub struct LinuxAuditSystemProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl LinuxAuditSystemProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> LinuxAuditSystemProvider {
+        LinuxAuditSystemProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn is_enabled(&self) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_enabled()),
+            shell: Box::new(move |b| s.is_enabled(b)),
+        })
+    }
+
+    pub fn is_running(&self) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_running()),
+            shell: Box::new(move |b| s.is_running(b)),
+        })
+    }
+
+    pub fn has_rule(&self, rule: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.has_rule(rule)),
+            shell: Box::new(move |b| s.has_rule(rule, b)),
+        })
+    }
+}
+
+pub mod inline;
+pub mod shell;
@@ -0,0 +1,66 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::linux_audit_system::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Auditctl;
+
+impl ShellProvider for Auditctl {
+    fn is_enabled(&self, b: &dyn Backend) -> Result<Output, Error> {
+        // 2 means enabled and locked against changes until the next reboot.
+        let enabled = self.status(b, "enabled")?;
+        Ok(Output::Bool(enabled == "1" || enabled == "2"))
+    }
+
+    fn is_running(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let pid = self.status(b, "pid")?;
+        Ok(Output::Bool(!pid.is_empty() && pid != "0"))
+    }
+
+    fn has_rule(&self, rule: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new("auditctl -l");
+        let res = b.run_command(c)?;
+
+        let rule = normalize(rule);
+        let found = res.stdout.lines().any(|l| normalize(l) == rule);
+        Ok(Output::Bool(found))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+impl Auditctl {
+    // auditctl -s prints one "key value" pair per line, while older
+    // versions print a single "AUDIT_STATUS: key=value ..." line.
+    fn status(&self, b: &dyn Backend, key: &str) -> Result<String, Error> {
+        let c = Command::new("auditctl -s");
+        let res = b.run_command(c)?;
+
+        for line in res.stdout.lines() {
+            let mut fields = line.split_whitespace();
+            if fields.next() == Some(key) {
+                return Ok(fields.next().unwrap_or("").to_string());
+            }
+
+            for field in line.split_whitespace() {
+                if let Some((k, v)) = field.split_once('=') {
+                    if k == key {
+                        return Ok(v.to_string());
+                    }
+                }
+            }
+        }
+
+        Ok("".to_string())
+    }
+}
+
+fn normalize(rule: &str) -> String {
+    rule.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -9,6 +9,17 @@ pub struct Providers {
     pub package: Box<package::PackageProvider>,
     pub service: Box<service::ServiceProvider>,
     pub port: Box<port::PortProvider>,
+    pub host: Box<host::HostProvider>,
+    pub docker: Box<docker::DockerProvider>,
+    pub linux_audit_system: Box<linux_audit_system::LinuxAuditSystemProvider>,
+    pub cgroup: Box<cgroup::CgroupProvider>,
+    pub bond: Box<bond::BondProvider>,
+    pub bridge: Box<bridge::BridgeProvider>,
+    pub repository: Box<repository::RepositoryProvider>,
+    pub pip_package: Box<package::PackageProvider>,
+    pub gem_package: Box<package::PackageProvider>,
+    pub npm_package: Box<package::PackageProvider>,
+    pub cargo_package: Box<package::PackageProvider>,
 }
 
 pub struct HandleFunc {
@@ -22,6 +33,7 @@ pub enum Output {
     I64(i64),
     Bool(bool),
     Text(String),
+    List(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -74,10 +86,24 @@ impl Output {
             _ => Err(OutputError.into()),
         }
     }
+
+    pub fn to_vec(o: Output) -> Result<Vec<String>, error::Error> {
+        match o {
+            Output::List(l) => Ok(l),
+            _ => Err(OutputError.into()),
+        }
+    }
 }
 
+pub mod bond;
+pub mod bridge;
+pub mod cgroup;
+pub mod docker;
 pub mod error;
 pub mod file;
+pub mod host;
+pub mod linux_audit_system;
 pub mod package;
 pub mod port;
+pub mod repository;
 pub mod service;
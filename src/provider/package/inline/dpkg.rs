@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::fs;
+use std::result::Result;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::provider::error::Error;
+use crate::provider::package::inline::InlineProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
+use crate::provider::Output;
+
+const STATUS: &str = "/var/lib/dpkg/status";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DpkgPackage {
+    pub name: String,
+    pub architecture: String,
+    pub version: String,
+    pub status: String,
+}
+
+impl DpkgPackage {
+    pub fn is_installed(&self) -> bool {
+        self.status == "install ok installed" || self.status == "hold ok installed"
+    }
+}
+
+// Packages in /var/lib/dpkg/status by name. A name maps to more than one
+// package when several architectures of it are known to dpkg.
+pub fn parse_status(contents: &str) -> HashMap<String, Vec<DpkgPackage>> {
+    let mut packages: HashMap<String, Vec<DpkgPackage>> = HashMap::new();
+
+    for stanza in contents.split("\n\n") {
+        let mut package = DpkgPackage {
+            name: String::new(),
+            architecture: String::new(),
+            version: String::new(),
+            status: String::new(),
+        };
+        for line in stanza.lines() {
+            match line.split_once(": ") {
+                Some(("Package", v)) => package.name = v.trim().to_string(),
+                Some(("Architecture", v)) => package.architecture = v.trim().to_string(),
+                Some(("Version", v)) => package.version = v.trim().to_string(),
+                Some(("Status", v)) => package.status = v.trim().to_string(),
+                _ => (),
+            }
+        }
+
+        if !package.name.is_empty() {
+            packages
+                .entry(package.name.clone())
+                .or_default()
+                .push(package);
+        }
+    }
+
+    packages
+}
+
+struct Index {
+    modified: SystemTime,
+    packages: HashMap<String, Vec<DpkgPackage>>,
+}
+
+// The status file is parsed once and reused until dpkg rewrites it.
+static INDEX: Mutex<Option<Index>> = Mutex::new(None);
+
+#[derive(Clone, Debug)]
+pub struct Dpkg;
+
+impl Dpkg {
+    fn with_index<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&HashMap<String, Vec<DpkgPackage>>) -> T,
+    {
+        let modified = fs::metadata(STATUS)?.modified()?;
+        let mut index = INDEX.lock().unwrap_or_else(|e| e.into_inner());
+
+        let packages = match index.take() {
+            Some(i) if i.modified == modified => i.packages,
+            _ => parse_status(&fs::read_to_string(STATUS)?),
+        };
+        let res = f(&packages);
+        *index = Some(Index { modified, packages });
+
+        Ok(res)
+    }
+
+    // Known packages by a name that may be qualified as "name:arch".
+    fn known(&self, name: &str) -> Result<Vec<DpkgPackage>, Error> {
+        let (name, architecture) = match name.split_once(':') {
+            Some((n, a)) => (n, Some(a)),
+            None => (name, None),
+        };
+        self.with_index(|packages| {
+            packages
+                .get(name)
+                .map(|p| {
+                    p.iter()
+                        .filter(|p| match architecture {
+                            Some(a) => p.architecture == a,
+                            None => true,
+                        })
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    fn installed(&self, name: &str) -> Result<Vec<DpkgPackage>, Error> {
+        let known = self.known(name)?;
+        Ok(known.into_iter().filter(|p| p.is_installed()).collect())
+    }
+}
+
+impl InlineProvider for Dpkg {
+    fn is_installed(&self, name: &str, version: Option<&str>) -> Result<Output, Error> {
+        let installed = self.installed(name)?;
+        let res = match version {
+            Some(v) => installed.iter().any(|p| p.version == v),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed(name)?
+                .first()
+                .map(|p| p.version.clone())
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn version_satisfies(&self, name: &str, requirement: &str) -> Result<Output, Error> {
+        let installed = Output::to_string(self.version(name, None)?)?;
+        let res = version::satisfies(&installed, requirement, Scheme::Debian)?;
+        Ok(Output::Bool(res))
+    }
+
+    fn is_held(&self, name: &str) -> Result<Output, Error> {
+        let res = self
+            .known(name)?
+            .iter()
+            .any(|p| p.status.starts_with("hold "));
+        Ok(Output::Bool(res))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}:{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -37,6 +37,77 @@ pub trait InlineProvider: Debug {
         Err(e.into())
     }
 
+    fn upgrade(&self, _: &str, _: Option<&str>) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "upgrade".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn version_satisfies(&self, _: &str, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "version_satisfies".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn packages(&self) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "packages".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn upgradable(&self) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "upgradable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn hold(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "hold".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn unhold(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "unhold".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_held(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_held".to_string(),
+        };
+        Err(e.into())
+    }
+
+    // Package managers without multiarch support only handle packages of
+    // the native architecture, so they cannot be asked for another one.
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        match architecture {
+            Some(_) => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "qualify".to_string(),
+                };
+                Err(e.into())
+            }
+            None => Ok(name.to_string()),
+        }
+    }
+
     fn box_clone(&self) -> Box<dyn InlineProvider>;
 }
 
@@ -46,4 +117,6 @@ impl Clone for Box<dyn InlineProvider> {
     }
 }
 
+pub mod dpkg;
 pub mod null;
+pub mod rpmdb;
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::provider::error::Error;
+use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::package;
+use crate::provider::package::inline::InlineProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
+use crate::provider::Output;
+
+// Only the sqlite backend of rpm 4.16+ is read. Older Berkeley DB and ndb
+// databases are left to the shell provider.
+const RPMDB: &str = "/var/lib/rpm/rpmdb.sqlite";
+
+const TAG_NAME: u32 = 1000;
+const TAG_VERSION: u32 = 1001;
+const TAG_RELEASE: u32 = 1002;
+const TAG_EPOCH: u32 = 1003;
+const TAG_ARCH: u32 = 1022;
+
+const TYPE_INT32: u32 = 4;
+const TYPE_STRING: u32 = 6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RpmPackage {
+    pub name: String,
+    pub epoch: Option<u32>,
+    pub version: String,
+    pub release: String,
+    pub arch: String,
+}
+
+impl RpmPackage {
+    // Formatted like `rpm -q --qf '%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}'`.
+    pub fn evr(&self) -> String {
+        match self.epoch {
+            Some(e) => format!("{}:{}-{}", e, self.version, self.release),
+            None => format!("{}-{}", self.version, self.release),
+        }
+    }
+}
+
+// Parses a header blob as stored in the Packages table: the index entry and
+// data store lengths, the index entries and then the data store, without
+// the magic that precedes headers in rpm files.
+pub fn parse_header(blob: &[u8]) -> Option<RpmPackage> {
+    let be32 = |at: usize| -> Option<u32> {
+        let bytes = blob.get(at..at + 4)?;
+        Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+    let string = |at: usize| -> Option<String> {
+        let bytes = blob.get(at..)?;
+        let end = bytes.iter().position(|b| *b == 0)?;
+        Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    };
+
+    let entries = be32(0)? as usize;
+    let store = 8 + entries * 16;
+
+    let mut package = RpmPackage {
+        name: String::new(),
+        epoch: None,
+        version: String::new(),
+        release: String::new(),
+        arch: String::new(),
+    };
+    for i in 0..entries {
+        let entry = 8 + i * 16;
+        let tag = be32(entry)?;
+        let kind = be32(entry + 4)?;
+        let data = store + be32(entry + 8)? as usize;
+
+        match (tag, kind) {
+            (TAG_NAME, TYPE_STRING) => package.name = string(data)?,
+            (TAG_VERSION, TYPE_STRING) => package.version = string(data)?,
+            (TAG_RELEASE, TYPE_STRING) => package.release = string(data)?,
+            (TAG_ARCH, TYPE_STRING) => package.arch = string(data)?,
+            (TAG_EPOCH, TYPE_INT32) => package.epoch = Some(be32(data)?),
+            _ => (),
+        }
+    }
+
+    if package.name.is_empty() {
+        None
+    } else {
+        Some(package)
+    }
+}
+
+struct Index {
+    modified: SystemTime,
+    packages: HashMap<String, Vec<RpmPackage>>,
+}
+
+// The database is read once and reused until it changes. rpm keeps the
+// database in WAL mode, so writes may only touch the -wal file.
+static INDEX: Mutex<Option<Index>> = Mutex::new(None);
+
+#[derive(Clone, Debug)]
+pub struct Rpmdb;
+
+impl Rpmdb {
+    fn modified(&self) -> Result<SystemTime, Error> {
+        let mut modified = fs::metadata(RPMDB)?.modified()?;
+        if let Ok(m) = fs::metadata(format!("{}-wal", RPMDB)) {
+            modified = modified.max(m.modified()?);
+        }
+        Ok(modified)
+    }
+
+    fn read(&self) -> Result<HashMap<String, Vec<RpmPackage>>, Error> {
+        let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let conn = Connection::open_with_flags(RPMDB, flags)?;
+        let mut stmt = conn.prepare("SELECT blob FROM Packages")?;
+        let blobs = stmt.query_map([], |row| row.get::<_, Vec<u8>>(0))?;
+
+        let mut packages: HashMap<String, Vec<RpmPackage>> = HashMap::new();
+        for blob in blobs {
+            if let Some(p) = parse_header(&blob?) {
+                packages.entry(p.name.clone()).or_default().push(p);
+            }
+        }
+        Ok(packages)
+    }
+
+    fn with_index<T, F>(&self, func: &str, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&HashMap<String, Vec<RpmPackage>>) -> T,
+    {
+        if !Path::new(RPMDB).exists() {
+            let e = HandleFuncNotDefined {
+                provider: format!("{:?}", self),
+                func: func.to_string(),
+            };
+            return Err(e.into());
+        }
+
+        let modified = self.modified()?;
+        let mut index = INDEX.lock().unwrap_or_else(|e| e.into_inner());
+
+        let packages = match index.take() {
+            Some(i) if i.modified == modified => i.packages,
+            _ => self.read()?,
+        };
+        let res = f(&packages);
+        *index = Some(Index { modified, packages });
+
+        Ok(res)
+    }
+
+    // Installed packages by a name that may be qualified as "name.arch".
+    fn installed(&self, name: &str, func: &str) -> Result<Vec<RpmPackage>, Error> {
+        let (name, architecture) = package::split_rpm_architecture(name);
+        self.with_index(func, |packages| {
+            packages
+                .get(name)
+                .map(|p| {
+                    p.iter()
+                        .filter(|p| match architecture {
+                            Some(a) => p.arch == a,
+                            None => true,
+                        })
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl InlineProvider for Rpmdb {
+    fn is_installed(&self, name: &str, version: Option<&str>) -> Result<Output, Error> {
+        let installed = self.installed(name, "is_installed")?;
+        let res = match version {
+            Some(v) => installed.iter().any(|p| {
+                let evr = format!("{}:{}-{}", p.epoch.unwrap_or(0), p.version, p.release);
+                package::rpm_version_matches(&evr, v)
+            }),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed(name, "version")?
+                .first()
+                .map(|p| p.evr())
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn version_satisfies(&self, name: &str, requirement: &str) -> Result<Output, Error> {
+        let installed = Output::to_string(self.version(name, None)?)?;
+        let res = version::satisfies(&installed, requirement, Scheme::Rpm)?;
+        Ok(Output::Bool(res))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}.{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
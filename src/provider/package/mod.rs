@@ -2,6 +2,17 @@ use crate::provider::package::inline::InlineProvider;
 use crate::provider::package::shell::ShellProvider;
 use crate::provider::HandleFunc;
 
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PackageKind {
+    Os,
+    Pip,
+    Gem,
+    Npm,
+    Cargo,
+}
+
 pub struct PackageProvider {
     pub inline: Box<dyn InlineProvider>,
     pub shell: Box<dyn ShellProvider>,
@@ -12,42 +23,351 @@ impl PackageProvider {
         &self,
         name: &'static str,
         version: Option<&'static str>,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_installed(&i.qualify(name, architecture)?, version)),
+            shell: Box::new(move |b| s.is_installed(&s.qualify(name, architecture)?, version, b)),
+        })
+    }
+
+    pub fn version(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.version(&i.qualify(name, architecture)?, version)),
+            shell: Box::new(move |b| s.version(&s.qualify(name, architecture)?, version, b)),
+        })
+    }
+
+    pub fn remove(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.remove(&i.qualify(name, architecture)?, version)),
+            shell: Box::new(move |b| s.remove(&s.qualify(name, architecture)?, version, b)),
+        })
+    }
+
+    pub fn install(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.install(&i.qualify(name, architecture)?, version)),
+            shell: Box::new(move |b| s.install(&s.qualify(name, architecture)?, version, b)),
+        })
+    }
+
+    pub fn upgrade(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.upgrade(&i.qualify(name, architecture)?, version)),
+            shell: Box::new(move |b| s.upgrade(&s.qualify(name, architecture)?, version, b)),
+        })
+    }
+
+    pub fn version_satisfies(
+        &self,
+        name: &'static str,
+        requirement: &'static str,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || {
+                i.version_satisfies(&i.qualify(name, architecture)?, requirement)
+            }),
+            shell: Box::new(move |b| {
+                s.version_satisfies(&s.qualify(name, architecture)?, requirement, b)
+            }),
+        })
+    }
+
+    pub fn hold(&self, name: &'static str, architecture: Option<&'static str>) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.hold(&i.qualify(name, architecture)?)),
+            shell: Box::new(move |b| s.hold(&s.qualify(name, architecture)?, b)),
+        })
+    }
+
+    pub fn unhold(
+        &self,
+        name: &'static str,
+        architecture: Option<&'static str>,
     ) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.is_installed(name, version)),
-            shell: Box::new(move |b| s.is_installed(name, version, b)),
+            inline: Box::new(move || i.unhold(&i.qualify(name, architecture)?)),
+            shell: Box::new(move |b| s.unhold(&s.qualify(name, architecture)?, b)),
         })
     }
 
-    pub fn version(&self, name: &'static str, version: Option<&'static str>) -> Box<HandleFunc> {
+    pub fn is_held(
+        &self,
+        name: &'static str,
+        architecture: Option<&'static str>,
+    ) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.version(name, version)),
-            shell: Box::new(move |b| s.version(name, version, b)),
+            inline: Box::new(move || i.is_held(&i.qualify(name, architecture)?)),
+            shell: Box::new(move |b| s.is_held(&s.qualify(name, architecture)?, b)),
         })
     }
 
-    pub fn remove(&self, name: &'static str, version: Option<&'static str>) -> Box<HandleFunc> {
+    pub fn packages(&self) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.remove(name, version)),
-            shell: Box::new(move |b| s.remove(name, version, b)),
+            inline: Box::new(move || i.packages()),
+            shell: Box::new(move |b| s.packages(b)),
         })
     }
 
-    pub fn install(&self, name: &'static str, version: Option<&'static str>) -> Box<HandleFunc> {
+    pub fn upgradable(&self) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.install(name, version)),
-            shell: Box::new(move |b| s.install(name, version, b)),
+            inline: Box::new(move || i.upgradable()),
+            shell: Box::new(move |b| s.upgradable(b)),
+        })
+    }
+}
+
+// Whether an installed epoch:version-release satisfies `version`, which may
+// be given with or without the epoch and the release.
+pub fn rpm_version_matches(installed: &str, version: &str) -> bool {
+    let (epoch, evr) = match installed.split_once(':') {
+        Some((e, vr)) => (e, vr),
+        None => ("0", installed),
+    };
+    let (version, wanted_epoch) = match version.split_once(':') {
+        Some((e, v)) => (v, e),
+        None => (version, epoch),
+    };
+    if wanted_epoch != epoch {
+        return false;
+    }
+
+    evr == version || evr.split('-').next() == Some(version)
+}
+
+// Package listings are passed around as Output::List of JSON records with
+// name, version, architecture and, when known, repository.
+pub fn list_entry(
+    name: &str,
+    version: &str,
+    architecture: &str,
+    repository: Option<&str>,
+) -> String {
+    serde_json::json!({
+        "name": name,
+        "version": version,
+        "architecture": architecture,
+        "repository": repository,
+    })
+    .to_string()
+}
+
+// Makes `rpm -qa` print tab separated name, version and architecture.
+pub const RPM_QUERY_FORMAT: &str =
+    "%{NAME}\\t%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\\t%{ARCH}\\n";
+
+// Makes `dnf repoquery --installed` print the same fields as
+// RPM_QUERY_FORMAT, followed by the repository each package came from.
+pub const REPOQUERY_FORMAT: &str =
+    "%{name}\\t%{epoch}:%{version}-%{release}\\t%{arch}\\t%{from_repo}\\n";
+
+// Parses RPM_QUERY_FORMAT or REPOQUERY_FORMAT output. Packages without a
+// repository column are looked up in `repositories`, which is keyed by
+// name-version-release.arch.
+pub fn parse_rpm_query(stdout: &str, repositories: &HashMap<String, String>) -> Vec<String> {
+    let mut packages: Vec<(&str, &str, &str, Option<&str>)> = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let name = fields.next()?;
+            let version = fields.next()?;
+            let version = version.strip_prefix("0:").unwrap_or(version);
+            let arch = fields.next().unwrap_or("");
+            if name == "gpg-pubkey" {
+                return None;
+            }
+            let repository = match fields.next() {
+                Some(r) => Some(r),
+                None => repositories
+                    .get(&nvra(name, version, arch))
+                    .map(|r| r.as_str()),
+            };
+            Some((name, version, arch, repository.filter(|r| !r.is_empty())))
         })
+        .collect();
+    packages.sort();
+    packages
+        .into_iter()
+        .map(|(n, v, a, r)| list_entry(n, v, a, r))
+        .collect()
+}
+
+// `yumdb get from_repo` prints each package as name-version-release.arch,
+// possibly with an epoch, followed by an indented "from_repo = repo".
+pub fn parse_yumdb(stdout: &str) -> HashMap<String, String> {
+    let mut repositories = HashMap::new();
+    let mut package = None;
+    for line in stdout.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            package = Some(strip_epoch(line.trim()));
+            continue;
+        }
+        if let Some((key, repo)) = line.split_once('=') {
+            if key.trim() == "from_repo" {
+                if let Some(p) = package.take() {
+                    repositories.insert(p, repo.trim().to_string());
+                }
+            }
+        }
+    }
+    repositories
+}
+
+// `apt-cache policy` marks the installed version with "***", followed by
+// the sources it is available from, as in
+// "500 http://archive.ubuntu.com/ubuntu jammy-updates/main amd64 Packages".
+// The first source's suite is taken, /var/lib/dpkg/status not being one.
+pub fn parse_apt_policy(stdout: &str) -> HashMap<String, String> {
+    let mut repositories = HashMap::new();
+    let mut package: Option<&str> = None;
+    let mut installed = false;
+    for line in stdout.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            package = line.strip_suffix(':');
+            installed = false;
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            ["***", ..] => installed = true,
+            [_, source] if !source.starts_with('/') => installed = false,
+            [_, _, suite, ..] if installed => {
+                if let Some(p) = package.take() {
+                    let suite = suite.split('/').next().unwrap_or(suite);
+                    repositories.insert(p.to_string(), suite.to_string());
+                }
+            }
+            _ => (),
+        }
+    }
+    repositories
+}
+
+fn nvra(name: &str, version: &str, arch: &str) -> String {
+    let version = version.split_once(':').map_or(version, |(_, v)| v);
+    format!("{}-{}.{}", name, version, arch)
+}
+
+// Drops the epoch from "1:name-v-r.arch" or "name-1:v-r.arch".
+fn strip_epoch(nevra: &str) -> String {
+    match nevra.split_once(':') {
+        Some((head, tail)) => {
+            let head = head.trim_end_matches(|c: char| c.is_ascii_digit());
+            format!("{}{}", head, tail)
+        }
+        None => nevra.to_string(),
+    }
+}
+
+// `yum check-update` and `dnf check-update` print "name.arch version repo",
+// wrapping long lines, followed by an optional obsoleting packages section.
+pub fn parse_check_update(stdout: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for line in stdout.lines() {
+        if line.starts_with("Obsoleting") || line.starts_with("Security:") {
+            break;
+        }
+        tokens.extend(line.split_whitespace());
+    }
+
+    tokens
+        .chunks(3)
+        .filter(|c| c.len() == 3)
+        .map(|c| {
+            let (name, arch) = c[0].rsplit_once('.').unwrap_or((c[0], ""));
+            list_entry(name, c[1], arch, Some(c[2]))
+        })
+        .collect()
+}
+
+// Names of the packages in `yum versionlock list` or `dnf versionlock list`,
+// whose entries look like "0:nginx-1.14.1-9.el7.*" and
+// "nginx-1:1.14.1-9.el8.*" respectively.
+pub fn parse_versionlock(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with(['#', '!']))
+        .filter_map(|line| {
+            let nvr = line.strip_suffix(".*").unwrap_or(line);
+            let name = nvr.rsplitn(3, '-').nth(2)?;
+            let name = match name.split_once(':') {
+                Some((_, n)) => n,
+                None => name,
+            };
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+const RPM_ARCHITECTURES: &[&str] = &[
+    "noarch", "x86_64", "i386", "i486", "i586", "i686", "aarch64", "armv7hl", "ppc64", "ppc64le",
+    "s390x",
+];
+
+// Splits a "name.arch" qualified name. Package names may contain dots
+// themselves, so the suffix only counts when it is a known architecture.
+pub fn split_rpm_architecture(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once('.') {
+        Some((n, a)) if RPM_ARCHITECTURES.contains(&a) => (n, Some(a)),
+        _ => (name, None),
+    }
+}
+
+// The name-version.arch form yum and dnf take for a specific version.
+pub fn rpm_package_spec(name: &str, version: Option<&str>) -> String {
+    match (split_rpm_architecture(name), version) {
+        ((n, Some(a)), Some(v)) => format!("{}-{}.{}", n, v, a),
+        ((n, None), Some(v)) => format!("{}-{}", n, v),
+        (_, None) => name.to_string(),
     }
 }
 
 pub mod inline;
 pub mod shell;
+pub mod version;
@@ -0,0 +1,98 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Apk;
+
+impl Apk {
+    // `apk list --installed` prints lines like
+    // "nginx-1.24.0-r7 x86_64 {nginx} (BSD-2-Clause) [installed]".
+    fn installed_version(&self, name: &str, b: &dyn Backend) -> Result<Option<String>, Error> {
+        let c = Command::new(&format!("apk list --installed {}", name));
+        let res = b.run_command(c)?;
+        let prefix = format!("{}-", name);
+
+        let version = res.stdout.lines().find_map(|line| {
+            let package = line.split_whitespace().next()?;
+            let version = package.strip_prefix(&prefix)?;
+            if version.starts_with(|c: char| c.is_ascii_digit()) {
+                Some(version.to_string())
+            } else {
+                None
+            }
+        });
+        Ok(version)
+    }
+}
+
+impl ShellProvider for Apk {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let success = match version {
+            Some(v) => match self.installed_version(name, b) {
+                Ok(Some(installed)) => {
+                    installed == v || installed.rsplit_once("-r").map(|(i, _)| i) == Some(v)
+                }
+                _ => false,
+            },
+            None => {
+                let c = Command::new(&format!("apk info -e {}", name));
+                match b.run_command(c) {
+                    Ok(r) => r.success,
+                    Err(_) => false,
+                }
+            }
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self.installed_version(name, b)?.unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = match version {
+            Some(v) => [name, v].join("="),
+            None => name.to_owned(),
+        };
+        let c = Command::new(&format!("apk add {}", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("apk del {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = match version {
+            Some(v) => [name, v].join("="),
+            None => name.to_owned(),
+        };
+        let c = Command::new(&format!("apk add --upgrade {}", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
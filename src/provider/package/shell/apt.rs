@@ -1,14 +1,41 @@
 use crate::backend::command::Command;
 use crate::backend::Backend;
 use crate::provider::error::Error;
+use crate::provider::package;
 use crate::provider::package::shell::ShellProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
 use crate::provider::Output;
 
+use std::collections::HashMap;
 use std::result::Result;
 
 #[derive(Clone, Debug)]
 pub struct Apt;
 
+impl Apt {
+    // The suites the installed versions of `packages` come from, by package
+    // name as `apt-cache policy` prints it. Packages which are only known to
+    // dpkg, or an apt-cache which fails, leave the repository unknown.
+    fn repositories(
+        &self,
+        packages: &[(&str, &str, &str)],
+        b: &dyn Backend,
+    ) -> HashMap<String, String> {
+        let mut names: Vec<&str> = packages.iter().map(|p| p.0).collect();
+        names.dedup();
+        if names.is_empty() {
+            return HashMap::new();
+        }
+
+        let c = Command::new(&format!("apt-cache policy {}", names.join(" ")));
+        match b.run_command(c) {
+            Ok(r) => package::parse_apt_policy(&r.stdout),
+            Err(_) => HashMap::new(),
+        }
+    }
+}
+
 impl ShellProvider for Apt {
     fn is_installed(
         &self,
@@ -47,7 +74,7 @@ impl ShellProvider for Apt {
                     "dpkg-query -f '${{Status}} ${{Version}}' -W {}",
                     name
                 ));
-                c.pipe("sed -n 's/^install ok installed //p'");
+                c.pipe("sed -n -E 's/^(install|hold) ok installed //p'");
                 let res = b.run_command(c)?;
                 res.stdout
             }
@@ -82,6 +109,123 @@ impl ShellProvider for Apt {
         Ok(Output::Bool(success))
     }
 
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = match version {
+            Some(v) => [name, v].join("="),
+            None => name.to_owned(),
+        };
+        let c = Command::new(&format!(
+            "DEBIAN_FRONTEND='noninteractive' apt-get -y -o \
+                                       Dpkg::Options::='--force-confdef' -o \
+                                       Dpkg::Options::='--force-confold' install --only-upgrade {}",
+            package
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn version_satisfies(
+        &self,
+        name: &str,
+        requirement: &str,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.version(name, None, b) {
+            Ok(v) => Output::to_string(v)?,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+        let res = version::satisfies(&installed, requirement, Scheme::Debian)?;
+        Ok(Output::Bool(res))
+    }
+
+    fn packages(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(
+            "dpkg-query -W -f '${db:Status-Abbrev}\\t${Package}\\t${Version}\\t${Architecture}\\n'",
+        );
+        let res = b.run_command(c)?;
+
+        let mut packages: Vec<(&str, &str, &str)> = res
+            .stdout
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                match fields.as_slice() {
+                    [status, name, version, arch]
+                        if status.trim() == "ii" || status.trim() == "hi" =>
+                    {
+                        Some((*name, *version, *arch))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        packages.sort();
+
+        let repositories = self.repositories(&packages, b);
+        let list = packages
+            .iter()
+            .map(|(name, version, arch)| {
+                let repository = repositories
+                    .get(&format!("{}:{}", name, arch))
+                    .or_else(|| repositories.get(*name))
+                    .map(|r| r.as_str());
+                package::list_entry(name, version, arch, repository)
+            })
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    // `apt list --upgradable` prints
+    // "name/suite[,suite] version arch [upgradable from: version]".
+    fn upgradable(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new("apt list --upgradable 2> /dev/null");
+        let res = b.run_command(c)?;
+
+        let list = res
+            .stdout
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (name, suites) = fields.next()?.split_once('/')?;
+                let version = fields.next()?;
+                let arch = fields.next()?;
+                let suite = suites.split(',').next().unwrap_or("");
+                Some(package::list_entry(name, version, arch, Some(suite)))
+            })
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn hold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("apt-mark hold {}", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn unhold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("apt-mark unhold {}", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    // The selection is "hold" whether or not the package is installed.
+    fn is_held(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new(&format!("dpkg-query -f '${{Status}}' -W {}", name));
+        c.pipe("grep -q '^hold '");
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}:{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider> {
         Box::new((*self).clone())
     }
@@ -0,0 +1,150 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
+use crate::provider::Output;
+
+use std::collections::HashMap;
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Dnf;
+
+impl Dnf {
+    // Installed versions as epoch:version-release, one per installed
+    // instance of the package.
+    fn installed_versions(&self, name: &str, b: &dyn Backend) -> Result<Vec<String>, Error> {
+        let c = Command::new(&format!(
+            "rpm -q --qf '%{{EPOCHNUM}}:%{{VERSION}}-%{{RELEASE}}\\n' {}",
+            name
+        ));
+        let res = b.run_command(c)?;
+        Ok(res.stdout.lines().map(|l| l.trim().to_string()).collect())
+    }
+}
+
+impl ShellProvider for Dnf {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_versions(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed.iter().any(|i| package::rpm_version_matches(i, v)),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => {
+                let c = Command::new(&format!(
+                    "rpm -q --qf '%|EPOCH?{{%{{EPOCH}}:}}:{{}}|%{{VERSION}}-%{{RELEASE}}\\n' {}",
+                    name
+                ));
+                let res = b.run_command(c)?;
+                res.stdout.lines().next().unwrap_or("").to_string()
+            }
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("dnf -y remove {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = package::rpm_package_spec(name, version);
+        let c = Command::new(&format!("dnf -y install {}", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = package::rpm_package_spec(name, version);
+        let c = Command::new(&format!("dnf -y upgrade {}", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn version_satisfies(
+        &self,
+        name: &str,
+        requirement: &str,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.version(name, None, b) {
+            Ok(v) => Output::to_string(v)?,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+        let res = version::satisfies(&installed, requirement, Scheme::Rpm)?;
+        Ok(Output::Bool(res))
+    }
+
+    fn packages(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "dnf -q repoquery --installed --qf '{}'",
+            package::REPOQUERY_FORMAT
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::List(package::parse_rpm_query(
+            &res.stdout,
+            &HashMap::new(),
+        )))
+    }
+
+    // check-update exits with 100 when there are updates.
+    fn upgradable(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new("dnf -q check-update");
+        c.or("test $? -eq 100");
+        let res = b.run_command(c)?;
+        Ok(Output::List(package::parse_check_update(&res.stdout)))
+    }
+
+    fn hold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("dnf -y versionlock add {}", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn unhold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("dnf -y versionlock delete {}", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn is_held(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new("dnf -q versionlock list");
+        let res = b.run_command(c)?;
+        let (name, _) = package::split_rpm_architecture(name);
+        let held = package::parse_versionlock(&res.stdout);
+        Ok(Output::Bool(held.iter().any(|h| h == name)))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}.{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
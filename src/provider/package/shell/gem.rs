@@ -0,0 +1,116 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Gem;
+
+impl Gem {
+    // `gem list -e` prints "name (version, default: version, version platform)".
+    fn installed_versions(&self, name: &str, b: &dyn Backend) -> Result<Vec<String>, Error> {
+        let c = Command::new(&format!("gem list -e {}", name));
+        let res = b.run_command(c)?;
+        let prefix = format!("{} (", name);
+
+        let versions = res
+            .stdout
+            .lines()
+            .find_map(|l| l.strip_prefix(&prefix)?.strip_suffix(')'))
+            .map(|list| {
+                list.split(", ")
+                    .filter_map(|v| {
+                        let v = v.trim_start_matches("default:").trim();
+                        v.split_whitespace().next().map(|v| v.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(versions)
+    }
+
+    fn version_option(&self, version: Option<&str>) -> String {
+        match version {
+            Some(v) => format!(" -v '{}'", v),
+            None => "".to_owned(),
+        }
+    }
+}
+
+impl ShellProvider for Gem {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_versions(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed.iter().any(|i| i == v),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed_versions(name, b)?
+                .into_iter()
+                .next()
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "gem install {}{}",
+            name,
+            self.version_option(version)
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = match version {
+            Some(_) => Command::new(&format!(
+                "gem uninstall -x {}{}",
+                name,
+                self.version_option(version)
+            )),
+            None => Command::new(&format!("gem uninstall -x -a {}", name)),
+        };
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = match version {
+            Some(_) => Command::new(&format!(
+                "gem install {}{}",
+                name,
+                self.version_option(version)
+            )),
+            None => Command::new(&format!("gem update {}", name)),
+        };
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
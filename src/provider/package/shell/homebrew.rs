@@ -0,0 +1,100 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::error::StringError;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Homebrew;
+
+impl Homebrew {
+    // Installed versions of a formula or, failing that, of a cask. brew
+    // prints "name version..." with every installed version.
+    fn installed_versions(&self, name: &str, b: &dyn Backend) -> Result<Vec<String>, Error> {
+        let mut c = Command::new(&format!("brew list --formula --versions {}", name));
+        c.or(&format!("brew list --cask --versions {}", name));
+        let res = b.run_command(c)?;
+        let versions = res
+            .stdout
+            .split_whitespace()
+            .skip(1)
+            .map(|v| v.to_string())
+            .collect();
+        Ok(versions)
+    }
+
+    // brew installs only the latest version of a formula. Older versions
+    // are separate formulae, e.g. postgresql@14, named as the package.
+    fn formula(&self, name: &str, version: Option<&str>) -> Result<String, Error> {
+        match version {
+            Some(v) => Err(StringError {
+                string: format!(
+                    "brew cannot install version {} of {}, name a versioned formula instead",
+                    v, name
+                ),
+            }
+            .into()),
+            None => Ok(name.to_owned()),
+        }
+    }
+}
+
+impl ShellProvider for Homebrew {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_versions(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed.iter().any(|i| i == v),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed_versions(name, b)?
+                .into_iter()
+                .last()
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("brew install {}", self.formula(name, version)?));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("brew uninstall {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("brew upgrade {}", self.formula(name, version)?));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -38,6 +38,77 @@ pub trait ShellProvider: Debug {
         Err(e.into())
     }
 
+    fn upgrade(&self, _: &str, _: Option<&str>, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "upgrade".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn version_satisfies(&self, _: &str, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "version_satisfies".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn packages(&self, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "packages".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn upgradable(&self, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "upgradable".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn hold(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "hold".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn unhold(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "unhold".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_held(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_held".to_string(),
+        };
+        Err(e.into())
+    }
+
+    // Package managers without multiarch support only handle packages of
+    // the native architecture, so they cannot be asked for another one.
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        match architecture {
+            Some(_) => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "qualify".to_string(),
+                };
+                Err(e.into())
+            }
+            None => Ok(name.to_string()),
+        }
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider>;
 }
 
@@ -47,6 +118,16 @@ impl Clone for Box<dyn ShellProvider> {
     }
 }
 
+pub mod apk;
 pub mod apt;
+pub mod cargo;
+pub mod dnf;
+pub mod gem;
+pub mod homebrew;
+pub mod npm;
 pub mod null;
+pub mod pacman;
+pub mod pip;
+pub mod portage;
 pub mod yum;
+pub mod zypper;
@@ -0,0 +1,91 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+pub struct Npm;
+
+impl Npm {
+    fn installed_version(&self, name: &str, b: &dyn Backend) -> Result<String, Error> {
+        let c = Command::new(&format!("npm ls -g --depth=0 --json {}", name));
+        let res = b.run_command(c)?;
+        let v: Value = serde_json::from_str(&res.stdout)?;
+
+        let version = v
+            .get("dependencies")
+            .and_then(|d| d.get(name))
+            .and_then(|p| p.get("version"))
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        Ok(version.to_string())
+    }
+
+    fn package(&self, name: &str, version: Option<&str>) -> String {
+        match version {
+            Some(v) => [name, v].join("@"),
+            None => name.to_owned(),
+        }
+    }
+}
+
+impl ShellProvider for Npm {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_version(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed == v,
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self.installed_version(name, b)?,
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("npm install -g {}", self.package(name, version)));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("npm uninstall -g {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = match version {
+            Some(_) => Command::new(&format!("npm install -g {}", self.package(name, version))),
+            None => Command::new(&format!("npm update -g {}", name)),
+        };
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
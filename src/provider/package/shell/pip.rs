@@ -0,0 +1,90 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Pip;
+
+impl Pip {
+    fn installed_version(&self, name: &str, b: &dyn Backend) -> Result<String, Error> {
+        let c = Command::new(&format!("pip show {}", name));
+        let res = b.run_command(c)?;
+        let version = res
+            .stdout
+            .lines()
+            .find_map(|l| l.strip_prefix("Version:"))
+            .unwrap_or("")
+            .trim();
+        Ok(version.to_string())
+    }
+
+    fn requirement(&self, name: &str, version: Option<&str>) -> String {
+        match version {
+            Some(v) => [name, v].join("=="),
+            None => name.to_owned(),
+        }
+    }
+}
+
+impl ShellProvider for Pip {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_version(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed == v,
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self.installed_version(name, b)?,
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "pip install '{}'",
+            self.requirement(name, version)
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("pip uninstall -y {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "pip install --upgrade '{}'",
+            self.requirement(name, version)
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
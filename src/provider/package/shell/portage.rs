@@ -0,0 +1,103 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Portage;
+
+impl Portage {
+    // Installed packages are recorded as /var/db/pkg/<category>/<name>-<version>.
+    // `name` may be given with or without its category.
+    fn installed_versions(&self, name: &str, b: &dyn Backend) -> Result<Vec<String>, Error> {
+        let pattern = if name.contains('/') {
+            format!("/var/db/pkg/{}-[0-9]*", name)
+        } else {
+            format!("/var/db/pkg/*/{}-[0-9]*", name)
+        };
+        let c = Command::new(&format!("ls -1 -d {}", pattern));
+        let res = b.run_command(c)?;
+
+        let package = name.rsplit('/').next().unwrap_or(name);
+        let prefix = format!("{}-", package);
+        let versions = res
+            .stdout
+            .lines()
+            .filter_map(|l| l.trim().rsplit('/').next()?.strip_prefix(&prefix))
+            .map(|v| v.to_string())
+            .collect();
+        Ok(versions)
+    }
+
+    fn atom(&self, name: &str, version: Option<&str>) -> String {
+        match version {
+            Some(v) => format!("={}-{}", name, v),
+            None => name.to_owned(),
+        }
+    }
+}
+
+impl ShellProvider for Portage {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_versions(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed
+                .iter()
+                .any(|i| i == v || i.rsplit_once("-r").map(|(i, _)| i) == Some(v)),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed_versions(name, b)?
+                .into_iter()
+                .last()
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("emerge --quiet '{}'", self.atom(name, version)));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("emerge --quiet --depclean {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!(
+            "emerge --quiet --update '{}'",
+            self.atom(name, version)
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -1,9 +1,13 @@
 use crate::backend::command::Command;
 use crate::backend::Backend;
 use crate::provider::error::Error;
+use crate::provider::package;
 use crate::provider::package::shell::ShellProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
 use crate::provider::Output;
 
+use std::collections::HashMap;
 use std::result::Result;
 
 #[derive(Clone, Debug)]
@@ -20,6 +24,7 @@ impl ShellProvider for Yum {
 
         match version {
             Some(v) => {
+                let (name, _) = package::split_rpm_architecture(name);
                 let full_package = [name, v].join("-");
                 c.pipe(&format!("grep -w -- {}", full_package));
             }
@@ -37,9 +42,12 @@ impl ShellProvider for Yum {
         let v = match version {
             Some(v) => v.to_owned(),
             None => {
-                let c = Command::new(&format!("rpm -q --qf '%{{VERSION}}-%{{RELEASE}}' {}", name));
+                let c = Command::new(&format!(
+                    "rpm -q --qf '%|EPOCH?{{%{{EPOCH}}:}}:{{}}|%{{VERSION}}-%{{RELEASE}}\\n' {}",
+                    name
+                ));
                 let res = b.run_command(c)?;
-                res.stdout
+                res.stdout.lines().next().unwrap_or("").to_string()
             }
         };
         Ok(Output::Text(v))
@@ -55,15 +63,89 @@ impl ShellProvider for Yum {
     }
 
     fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
-        let package = match version {
-            Some(v) => [name, v].join("-"),
-            None => name.to_owned(),
-        };
+        let package = package::rpm_package_spec(name, version);
         let c = Command::new(&format!("yum -y install {}", package));
         let res = b.run_command(c)?;
         Ok(Output::Bool(res.success))
     }
 
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = package::rpm_package_spec(name, version);
+        let c = Command::new(&format!("yum -y update {}", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn version_satisfies(
+        &self,
+        name: &str,
+        requirement: &str,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.version(name, None, b) {
+            Ok(v) => Output::to_string(v)?,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+        let res = version::satisfies(&installed, requirement, Scheme::Rpm)?;
+        Ok(Output::Bool(res))
+    }
+
+    // yumdb only knows about packages yum installed, and needs read access
+    // to /var/lib/yum/yumdb, so repositories may be missing.
+    fn packages(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rpm -qa --qf '{}'", package::RPM_QUERY_FORMAT));
+        let res = b.run_command(c)?;
+        let repositories = match b.run_command(Command::new("yumdb get from_repo")) {
+            Ok(r) => package::parse_yumdb(&r.stdout),
+            Err(_) => HashMap::new(),
+        };
+        Ok(Output::List(package::parse_rpm_query(
+            &res.stdout,
+            &repositories,
+        )))
+    }
+
+    // check-update exits with 100 when there are updates.
+    fn upgradable(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new("yum -q check-update");
+        c.or("test $? -eq 100");
+        let res = b.run_command(c)?;
+        Ok(Output::List(package::parse_check_update(&res.stdout)))
+    }
+
+    fn hold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("yum -y versionlock add {}", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    // Entries are stored as epoch:name-version-release.arch and deleted by
+    // pattern rather than by package name.
+    fn unhold(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let pattern = match package::split_rpm_architecture(name) {
+            (n, Some(a)) => format!("*:{}-*.{}", n, a),
+            (n, None) => format!("*:{}-*", n),
+        };
+        let c = Command::new(&format!("yum -y versionlock delete '{}'", pattern));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn is_held(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new("yum -q versionlock list");
+        let res = b.run_command(c)?;
+        let (name, _) = package::split_rpm_architecture(name);
+        let held = package::parse_versionlock(&res.stdout);
+        Ok(Output::Bool(held.iter().any(|h| h == name)))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}.{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider> {
         Box::new((*self).clone())
     }
@@ -0,0 +1,112 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::package;
+use crate::provider::package::shell::ShellProvider;
+use crate::provider::package::version;
+use crate::provider::package::version::Scheme;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Zypper;
+
+impl Zypper {
+    // Installed versions as [epoch:]version-release, one per installed
+    // instance of the package.
+    fn installed_versions(&self, name: &str, b: &dyn Backend) -> Result<Vec<String>, Error> {
+        let c = Command::new(&format!(
+            "rpm -q --qf '%|EPOCH?{{%{{EPOCH}}:}}:{{}}|%{{VERSION}}-%{{RELEASE}}\\n' {}",
+            name
+        ));
+        let res = b.run_command(c)?;
+        Ok(res.stdout.lines().map(|l| l.trim().to_string()).collect())
+    }
+}
+
+impl ShellProvider for Zypper {
+    fn is_installed(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.installed_versions(name, b) {
+            Ok(v) => v,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+
+        let res = match version {
+            Some(v) => installed.iter().any(|i| package::rpm_version_matches(i, v)),
+            None => !installed.is_empty(),
+        };
+        Ok(Output::Bool(res))
+    }
+
+    fn version(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let v = match version {
+            Some(v) => v.to_owned(),
+            None => self
+                .installed_versions(name, b)?
+                .into_iter()
+                .next()
+                .unwrap_or_default(),
+        };
+        Ok(Output::Text(v))
+    }
+
+    fn install(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = match version {
+            Some(v) => [name, v].join("="),
+            None => name.to_owned(),
+        };
+        let c = Command::new(&format!("zypper -n install '{}'", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, _version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("zypper -n remove {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn upgrade(&self, name: &str, version: Option<&str>, b: &dyn Backend) -> Result<Output, Error> {
+        let package = match version {
+            Some(v) => [name, v].join("="),
+            None => name.to_owned(),
+        };
+        let c = Command::new(&format!("zypper -n update '{}'", package));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn version_satisfies(
+        &self,
+        name: &str,
+        requirement: &str,
+        b: &dyn Backend,
+    ) -> Result<Output, Error> {
+        let installed = match self.version(name, None, b) {
+            Ok(v) => Output::to_string(v)?,
+            Err(_) => return Ok(Output::Bool(false)),
+        };
+        let res = version::satisfies(&installed, requirement, Scheme::Rpm)?;
+        Ok(Output::Bool(res))
+    }
+
+    fn qualify(&self, name: &str, architecture: Option<&str>) -> Result<String, Error> {
+        Ok(match architecture {
+            Some(a) => format!("{}.{}", name, a),
+            None => name.to_string(),
+        })
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::result::Result;
+
+use crate::provider::error::Error;
+use crate::provider::error::StringError;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scheme {
+    Debian,
+    Rpm,
+}
+
+impl Scheme {
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match *self {
+            Scheme::Debian => compare_debian(a, b),
+            Scheme::Rpm => compare_rpm(a, b),
+        }
+    }
+}
+
+// Whether `installed` satisfies every comma separated constraint of
+// `requirement`, e.g. ">= 1.2.3, < 2". A constraint without an operator
+// must match exactly.
+pub fn satisfies(installed: &str, requirement: &str, scheme: Scheme) -> Result<bool, Error> {
+    if installed.is_empty() {
+        return Ok(false);
+    }
+
+    for constraint in requirement.split(',') {
+        let constraint = constraint.trim();
+        let split = constraint
+            .find(|c: char| !matches!(c, '<' | '>' | '=' | '!'))
+            .unwrap_or(constraint.len());
+        let (op, version) = constraint.split_at(split);
+        let version = version.trim();
+        if version.is_empty() {
+            return Err(StringError {
+                string: format!("Invalid version requirement: {}", requirement),
+            }
+            .into());
+        }
+
+        let ord = scheme.compare(installed, version);
+        let ok = match op {
+            "" | "=" | "==" => ord == Ordering::Equal,
+            "!=" => ord != Ordering::Equal,
+            ">" | ">>" => ord == Ordering::Greater,
+            ">=" => ord != Ordering::Less,
+            "<" | "<<" => ord == Ordering::Less,
+            "<=" => ord != Ordering::Greater,
+            _ => {
+                return Err(StringError {
+                    string: format!("Invalid version operator: {}", op),
+                }
+                .into())
+            }
+        };
+        if !ok {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn split_epoch(version: &str) -> (u64, &str) {
+    match version.split_once(':') {
+        Some((e, rest)) if e.chars().all(|c| c.is_ascii_digit()) => (e.parse().unwrap_or(0), rest),
+        _ => (0, version),
+    }
+}
+
+// [epoch:]upstream_version[-debian_revision] as compared by dpkg.
+pub fn compare_debian(a: &str, b: &str) -> Ordering {
+    let (a_epoch, a) = split_epoch(a);
+    let (b_epoch, b) = split_epoch(b);
+    let (a_upstream, a_revision) = a.rsplit_once('-').unwrap_or((a, ""));
+    let (b_upstream, b_revision) = b.rsplit_once('-').unwrap_or((b, ""));
+
+    a_epoch
+        .cmp(&b_epoch)
+        .then_with(|| verrevcmp(a_upstream, b_upstream))
+        .then_with(|| verrevcmp(a_revision, b_revision))
+}
+
+// Letters sort before non-letters and '~' before anything, even the end
+// of the string.
+fn debian_order(c: Option<u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(b'~') => -1,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+fn verrevcmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        while (i < a.len() && !a[i].is_ascii_digit()) || (j < b.len() && !b[j].is_ascii_digit()) {
+            let ac = debian_order(a.get(i).copied());
+            let bc = debian_order(b.get(j).copied());
+            if ac != bc {
+                return ac.cmp(&bc);
+            }
+            i += 1;
+            j += 1;
+        }
+
+        while i < a.len() && a[i] == b'0' {
+            i += 1;
+        }
+        while j < b.len() && b[j] == b'0' {
+            j += 1;
+        }
+
+        let mut first_diff = Ordering::Equal;
+        while i < a.len() && a[i].is_ascii_digit() && j < b.len() && b[j].is_ascii_digit() {
+            if first_diff == Ordering::Equal {
+                first_diff = a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+        if i < a.len() && a[i].is_ascii_digit() {
+            return Ordering::Greater;
+        }
+        if j < b.len() && b[j].is_ascii_digit() {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+
+    Ordering::Equal
+}
+
+// [epoch:]version[-release] as compared by rpm. The releases are only
+// compared when both sides have one.
+pub fn compare_rpm(a: &str, b: &str) -> Ordering {
+    let (a_epoch, a) = split_epoch(a);
+    let (b_epoch, b) = split_epoch(b);
+    let (a_version, a_release) = match a.rsplit_once('-') {
+        Some((v, r)) => (v, Some(r)),
+        None => (a, None),
+    };
+    let (b_version, b_release) = match b.rsplit_once('-') {
+        Some((v, r)) => (v, Some(r)),
+        None => (b, None),
+    };
+
+    a_epoch
+        .cmp(&b_epoch)
+        .then_with(|| rpmvercmp(a_version, b_version))
+        .then_with(|| match (a_release, b_release) {
+            (Some(a), Some(b)) => rpmvercmp(a, b),
+            _ => Ordering::Equal,
+        })
+}
+
+pub fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    let separator = |c: u8| !c.is_ascii_alphanumeric() && c != b'~' && c != b'^';
+
+    loop {
+        while i < a.len() && separator(a[i]) {
+            i += 1;
+        }
+        while j < b.len() && separator(b[j]) {
+            j += 1;
+        }
+
+        // '~' sorts before everything, even the end of the version.
+        if a.get(i) == Some(&b'~') || b.get(j) == Some(&b'~') {
+            if a.get(i) != Some(&b'~') {
+                return Ordering::Greater;
+            }
+            if b.get(j) != Some(&b'~') {
+                return Ordering::Less;
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        // '^' sorts after the end of the version but before anything else.
+        if a.get(i) == Some(&b'^') || b.get(j) == Some(&b'^') {
+            if i == a.len() {
+                return Ordering::Less;
+            }
+            if j == b.len() {
+                return Ordering::Greater;
+            }
+            if a[i] != b'^' {
+                return Ordering::Greater;
+            }
+            if b[j] != b'^' {
+                return Ordering::Less;
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        if i == a.len() || j == b.len() {
+            break;
+        }
+
+        let numeric = a[i].is_ascii_digit();
+        let segment = |s: &[u8], start: usize| {
+            let mut end = start;
+            while end < s.len()
+                && (if numeric {
+                    s[end].is_ascii_digit()
+                } else {
+                    s[end].is_ascii_alphabetic()
+                })
+            {
+                end += 1;
+            }
+            end
+        };
+        let (a_end, b_end) = (segment(a, i), segment(b, j));
+
+        // A numeric segment is newer than an alphabetic one.
+        if b_end == j {
+            return if numeric {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+
+        let (mut a_seg, mut b_seg) = (&a[i..a_end], &b[j..b_end]);
+        if numeric {
+            while a_seg.first() == Some(&b'0') {
+                a_seg = &a_seg[1..];
+            }
+            while b_seg.first() == Some(&b'0') {
+                b_seg = &b_seg[1..];
+            }
+            if a_seg.len() != b_seg.len() {
+                return a_seg.len().cmp(&b_seg.len());
+            }
+        }
+        match a_seg.cmp(b_seg) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        i = a_end;
+        j = b_end;
+    }
+
+    match (i == a.len(), j == b.len()) {
+        (true, true) => Ordering::Equal,
+        (false, _) => Ordering::Greater,
+        _ => Ordering::Less,
+    }
+}
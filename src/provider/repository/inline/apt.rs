@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::error::Error;
+use crate::provider::repository;
+use crate::provider::repository::inline::InlineProvider;
+use crate::provider::repository::Repository;
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Apt;
+
+impl Apt {
+    fn repositories(&self) -> Result<Vec<Repository>, Error> {
+        let mut repositories = Vec::new();
+
+        let list = Path::new("/etc/apt/sources.list");
+        if list.exists() {
+            repositories.extend(repository::parse_apt_list(&fs::read_to_string(list)?));
+        }
+
+        let dir = Path::new("/etc/apt/sources.list.d");
+        if dir.is_dir() {
+            let mut paths = Vec::new();
+            for entry in fs::read_dir(dir)? {
+                paths.push(entry?.path());
+            }
+            paths.sort();
+
+            for path in paths {
+                match path.extension().and_then(|e| e.to_str()) {
+                    Some("list") => {
+                        repositories.extend(repository::parse_apt_list(&fs::read_to_string(&path)?))
+                    }
+                    Some("sources") => repositories
+                        .extend(repository::parse_apt_sources(&fs::read_to_string(&path)?)),
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(repositories)
+    }
+}
+
+impl InlineProvider for Apt {
+    fn exists(&self, name: &str) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories()?, name);
+        Ok(Output::Bool(found.is_some()))
+    }
+
+    fn is_enabled(&self, name: &str) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories()?, name);
+        Ok(Output::Bool(found.unwrap_or(false)))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
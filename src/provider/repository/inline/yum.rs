@@ -0,0 +1,51 @@
+use std::fs;
+use std::path::Path;
+use std::result::Result;
+
+use crate::provider::error::Error;
+use crate::provider::repository;
+use crate::provider::repository::inline::InlineProvider;
+use crate::provider::repository::Repository;
+use crate::provider::Output;
+
+#[derive(Clone, Debug)]
+pub struct Yum;
+
+impl Yum {
+    fn repositories(&self) -> Result<Vec<Repository>, Error> {
+        let mut repositories = Vec::new();
+
+        let dir = Path::new("/etc/yum.repos.d");
+        if dir.is_dir() {
+            let mut paths = Vec::new();
+            for entry in fs::read_dir(dir)? {
+                paths.push(entry?.path());
+            }
+            paths.sort();
+
+            for path in paths {
+                if path.extension().and_then(|e| e.to_str()) == Some("repo") {
+                    repositories.extend(repository::parse_yum_repos(&fs::read_to_string(&path)?));
+                }
+            }
+        }
+
+        Ok(repositories)
+    }
+}
+
+impl InlineProvider for Yum {
+    fn exists(&self, name: &str) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories()?, name);
+        Ok(Output::Bool(found.is_some()))
+    }
+
+    fn is_enabled(&self, name: &str) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories()?, name);
+        Ok(Output::Bool(found.unwrap_or(false)))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
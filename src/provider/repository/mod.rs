@@ -0,0 +1,242 @@
+use crate::provider::repository::inline::InlineProvider;
+use crate::provider::repository::shell::ShellProvider;
+use crate::provider::HandleFunc;
+
+pub struct RepositoryProvider {
+    pub inline: Box<dyn InlineProvider>,
+    pub shell: Box<dyn ShellProvider>,
+}
+
+impl RepositoryProvider {
+    pub fn new(i: Box<dyn InlineProvider>, s: Box<dyn ShellProvider>) -> RepositoryProvider {
+        RepositoryProvider {
+            inline: i,
+            shell: s,
+        }
+    }
+
+    pub fn exists(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.exists(name)),
+            shell: Box::new(move |b| s.exists(name, b)),
+        })
+    }
+
+    pub fn is_enabled(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.is_enabled(name)),
+            shell: Box::new(move |b| s.is_enabled(name, b)),
+        })
+    }
+
+    pub fn add(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.add(name)),
+            shell: Box::new(move |b| s.add(name, b)),
+        })
+    }
+
+    pub fn remove(&self, name: &'static str) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.remove(name)),
+            shell: Box::new(move |b| s.remove(name, b)),
+        })
+    }
+}
+
+// A configured repository, known by every name that can refer to it: its
+// URIs and, for yum, its section id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Repository {
+    pub names: Vec<String>,
+    pub enabled: bool,
+}
+
+// Some(enabled) when any repository matches `name`, None otherwise.
+pub fn lookup(repositories: &[Repository], name: &str) -> Option<bool> {
+    let matched = matching(repositories, name);
+
+    if matched.is_empty() {
+        None
+    } else {
+        Some(matched.iter().any(|r| r.enabled))
+    }
+}
+
+pub fn matching<'a>(repositories: &'a [Repository], name: &str) -> Vec<&'a Repository> {
+    let candidates = candidates(name);
+    repositories
+        .iter()
+        .filter(|r| r.names.iter().any(|n| candidates.contains(&normalize(n))))
+        .collect()
+}
+
+fn candidates(name: &str) -> Vec<String> {
+    match name.strip_prefix("ppa:") {
+        Some(ppa) => vec![
+            format!("ppa.launchpad.net/{}/ubuntu", ppa),
+            format!("ppa.launchpadcontent.net/{}/ubuntu", ppa),
+        ],
+        None => vec![normalize(name)],
+    }
+}
+
+fn normalize(name: &str) -> String {
+    let name = name.trim();
+    let name = name
+        .strip_prefix("http://")
+        .or_else(|| name.strip_prefix("https://"))
+        .unwrap_or(name);
+    name.trim_end_matches('/').to_string()
+}
+
+// One-line style entries of sources.list and sources.list.d/*.list.
+// Commented out entries are reported as disabled.
+pub fn parse_apt_list(contents: &str) -> Vec<Repository> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let (enabled, line) = match line.strip_prefix('#') {
+                Some(l) => (false, l),
+                None => (true, line),
+            };
+            let line = match line.find('#') {
+                Some(i) => &line[..i],
+                None => line,
+            };
+
+            let mut fields = line.split_whitespace();
+            match fields.next() {
+                Some("deb") | Some("deb-src") => (),
+                _ => return None,
+            }
+            let mut uri = fields.next()?;
+            if uri.starts_with('[') {
+                while !uri.ends_with(']') {
+                    uri = fields.next()?;
+                }
+                uri = fields.next()?;
+            }
+            fields.next()?;
+
+            Some(Repository {
+                names: vec![uri.to_string()],
+                enabled,
+            })
+        })
+        .collect()
+}
+
+// deb822 style stanzas of sources.list.d/*.sources.
+pub fn parse_apt_sources(contents: &str) -> Vec<Repository> {
+    let mut repositories = Vec::new();
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for line in contents.lines().chain(std::iter::once("")) {
+        if line.trim().is_empty() {
+            if let Some(r) = apt_stanza(&fields) {
+                repositories.push(r);
+            }
+            fields.clear();
+        } else if line.starts_with('#') {
+            continue;
+        } else if line.starts_with(char::is_whitespace) {
+            if let Some(field) = fields.last_mut() {
+                field.1 = format!("{} {}", field.1, line.trim());
+            }
+        } else if let Some((k, v)) = line.split_once(':') {
+            fields.push((k.trim().to_lowercase(), v.trim().to_string()));
+        }
+    }
+
+    repositories
+}
+
+fn apt_stanza(fields: &[(String, String)]) -> Option<Repository> {
+    let field = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    };
+
+    let types = field("types")?;
+    if !types
+        .split_whitespace()
+        .any(|t| t == "deb" || t == "deb-src")
+    {
+        return None;
+    }
+    let names: Vec<String> = field("uris")?
+        .split_whitespace()
+        .map(|u| u.to_string())
+        .collect();
+    let enabled = !matches!(field("enabled"), Some(v) if v.eq_ignore_ascii_case("no"));
+
+    Some(Repository { names, enabled })
+}
+
+// Sections of yum.repos.d/*.repo.
+pub fn parse_yum_repos(contents: &str) -> Vec<Repository> {
+    let mut repositories: Vec<Repository> = Vec::new();
+    let mut key = String::new();
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            repositories.push(Repository {
+                names: vec![trimmed[1..trimmed.len() - 1].trim().to_string()],
+                enabled: true,
+            });
+            key.clear();
+            continue;
+        }
+
+        let repository = match repositories.last_mut() {
+            Some(r) => r,
+            None => continue,
+        };
+        let value = if line.starts_with(char::is_whitespace) {
+            trimmed
+        } else {
+            match trimmed.split_once('=') {
+                Some((k, v)) => {
+                    key = k.trim().to_lowercase();
+                    v.trim()
+                }
+                None => continue,
+            }
+        };
+
+        match key.as_str() {
+            "enabled" => {
+                repository.enabled =
+                    !matches!(value.to_lowercase().as_str(), "0" | "false" | "no" | "off")
+            }
+            "baseurl" | "mirrorlist" | "metalink" => repository.names.extend(
+                value
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|u| !u.is_empty())
+                    .map(|u| u.to_string()),
+            ),
+            _ => (),
+        }
+    }
+
+    repositories
+}
+
+pub mod inline;
+pub mod shell;
@@ -0,0 +1,62 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::repository;
+use crate::provider::repository::shell::ShellProvider;
+use crate::provider::repository::Repository;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Apt;
+
+impl Apt {
+    fn repositories(&self, b: &dyn Backend) -> Result<Vec<Repository>, Error> {
+        let c = Command::new(
+            "cat /etc/apt/sources.list /etc/apt/sources.list.d/*.list 2> /dev/null || true",
+        );
+        let res = b.run_command(c)?;
+        let mut repositories = repository::parse_apt_list(&res.stdout);
+
+        let c = Command::new(
+            "for f in /etc/apt/sources.list.d/*.sources; \
+             do if test -f \"$f\"; then cat \"$f\"; echo; fi; done",
+        );
+        let res = b.run_command(c)?;
+        repositories.extend(repository::parse_apt_sources(&res.stdout));
+
+        Ok(repositories)
+    }
+}
+
+impl ShellProvider for Apt {
+    fn exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories(b)?, name);
+        Ok(Output::Bool(found.is_some()))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories(b)?, name);
+        Ok(Output::Bool(found.unwrap_or(false)))
+    }
+
+    fn add(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("add-apt-repository -y '{}'", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn remove(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("add-apt-repository -y -r '{}'", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::repository;
+use crate::provider::repository::shell::ShellProvider;
+use crate::provider::repository::Repository;
+use crate::provider::Output;
+
+use std::result::Result;
+
+#[derive(Clone, Debug)]
+pub struct Yum;
+
+impl Yum {
+    fn repositories(&self, b: &dyn Backend) -> Result<Vec<Repository>, Error> {
+        let c = Command::new("cat /etc/yum.repos.d/*.repo 2> /dev/null || true");
+        let res = b.run_command(c)?;
+        Ok(repository::parse_yum_repos(&res.stdout))
+    }
+}
+
+impl ShellProvider for Yum {
+    fn exists(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories(b)?, name);
+        Ok(Output::Bool(found.is_some()))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let found = repository::lookup(&self.repositories(b)?, name);
+        Ok(Output::Bool(found.unwrap_or(false)))
+    }
+
+    fn add(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("yum-config-manager --add-repo '{}'", name));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    // Removes the sections which define the repository, either by its id
+    // or by its baseurl, leaving other repositories in the same .repo file
+    // alone.
+    fn remove(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let repositories = self.repositories(b)?;
+        let ids: Vec<&str> = repository::matching(&repositories, name)
+            .iter()
+            .map(|r| r.names[0].as_str())
+            .collect();
+        if ids.is_empty() {
+            return Ok(Output::Bool(false));
+        }
+
+        let scripts: Vec<String> = ids
+            .iter()
+            .map(|id| {
+                let header = format!("^\\[{}\\]$", sed_escape(id));
+                format!(
+                    "for f in $(grep -l -x -F '[{}]' /etc/yum.repos.d/*.repo); do \
+                     sed -i '/{}/,/^\\[/{{/{}/d;/^\\[/!d}}' \"$f\"; done",
+                    id, header, header
+                )
+            })
+            .collect();
+        let c = Command::new(&scripts.join(" && "));
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+fn sed_escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        if ".[]*^$\\/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
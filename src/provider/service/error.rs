@@ -1,11 +1,66 @@
 use crate::provider;
 
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum Error {
     DBus(dbus::Error),
     DBusArgTypeMismatch(dbus::arg::TypeMismatchError),
+    Job(JobError),
+    JobWaitTimeout { unit: String, timeout: Duration },
+    NotSupported { operation: String, init: String },
+}
+
+// Results systemd reports in JobRemoved for jobs that did not succeed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobResult {
+    Failed,
+    Timeout,
+    Dependency,
+    Canceled,
+    Other(String),
+}
+
+impl From<&str> for JobResult {
+    fn from(result: &str) -> JobResult {
+        match result {
+            "failed" => JobResult::Failed,
+            "timeout" => JobResult::Timeout,
+            "dependency" => JobResult::Dependency,
+            "canceled" => JobResult::Canceled,
+            r => JobResult::Other(r.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for JobResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JobResult::Failed => write!(f, "failed"),
+            JobResult::Timeout => write!(f, "timed out"),
+            JobResult::Dependency => write!(f, "failed because a dependency failed"),
+            JobResult::Canceled => write!(f, "was canceled"),
+            JobResult::Other(ref r) => write!(f, "finished with result {}", r),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct JobError {
+    pub unit: String,
+    pub result: JobResult,
+    pub journal: Vec<String>,
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Job for {} {}", self.unit, self.result)?;
+        for line in &self.journal {
+            write!(f, "\n{}", line)?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for Error {
@@ -13,6 +68,14 @@ impl fmt::Display for Error {
         match *self {
             Error::DBus(ref err) => err.fmt(f),
             Error::DBusArgTypeMismatch(ref err) => err.fmt(f),
+            Error::Job(ref err) => err.fmt(f),
+            Error::JobWaitTimeout { ref unit, timeout } => {
+                write!(f, "Job for {} did not finish within {:?}", unit, timeout)
+            }
+            Error::NotSupported {
+                ref operation,
+                ref init,
+            } => write!(f, "{} is not supported by {}", operation, init),
         }
     }
 }
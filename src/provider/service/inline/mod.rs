@@ -1,5 +1,7 @@
 use crate::provider::error::Error;
 use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::service::LogQuery;
+use crate::provider::service::Scope;
 use crate::provider::Output;
 
 use std::fmt::Debug;
@@ -69,6 +71,75 @@ pub trait InlineProvider: Debug {
         Err(e.into())
     }
 
+    fn property(&self, _: &str, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "property".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn logs(&self, _: &str, _: &LogQuery) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "logs".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn services(&self) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "services".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn mask(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "mask".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn unmask(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "unmask".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_masked(&self, _: &str) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_masked".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn daemon_reload(&self) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "daemon_reload".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn InlineProvider>, Error> {
+        match scope {
+            Scope::System => Ok(self.box_clone()),
+            Scope::User(_) => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "scoped".to_string(),
+                };
+                Err(e.into())
+            }
+        }
+    }
+
     fn box_clone(&self) -> Box<dyn InlineProvider>;
 }
 
@@ -1,17 +1,243 @@
 use crate::provider::error::Error;
+use crate::provider::service;
 use crate::provider::service::inline::InlineProvider;
+use crate::provider::service::Scope;
 use crate::provider::Output;
 
-use dbus::arg::Variant;
+use dbus::arg::{ArgType, PropMap, RefArg, Variant};
 use dbus::ffidisp::{BusType, Connection, ConnectionItem};
 use dbus::{Message, Path};
+use nix::unistd::getuid;
 
+use std::collections::BTreeMap;
+use std::process;
 use std::result::Result;
+use std::time::{Duration, Instant};
 
+// Systemd's own DefaultTimeoutStartSec.
+const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_secs(90);
+
+// `job_timeout` bounds how long start, stop, reload and restart wait for
+// their job to finish.
+#[derive(Clone, Debug)]
+pub struct Systemd {
+    pub job_timeout: Duration,
+}
+
+impl Default for Systemd {
+    fn default() -> Systemd {
+        Systemd {
+            job_timeout: DEFAULT_JOB_TIMEOUT,
+        }
+    }
+}
+
+// Units of a user's service manager, reached through the user's own bus.
 #[derive(Clone, Debug)]
-pub struct Systemd;
+pub struct SystemdUser {
+    pub uid: u32,
+    pub job_timeout: Duration,
+}
+
+impl SystemdUser {
+    pub fn new(uid: u32) -> SystemdUser {
+        SystemdUser {
+            uid,
+            job_timeout: DEFAULT_JOB_TIMEOUT,
+        }
+    }
+}
 
 impl InlineProvider for Systemd {
+    fn is_running(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.is_running(name)
+    }
+
+    fn is_enabled(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.is_enabled(name)
+    }
+
+    fn enable(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.enable(name)
+    }
+
+    fn disable(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.disable(name)
+    }
+
+    fn start(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.start(name, self.job_timeout)
+    }
+
+    fn stop(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.stop(name, self.job_timeout)
+    }
+
+    fn reload(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.reload(name, self.job_timeout)
+    }
+
+    fn restart(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.restart(name, self.job_timeout)
+    }
+
+    fn property(&self, name: &str, property: &str) -> Result<Output, Error> {
+        Bus::System.property(name, property)
+    }
+
+    fn services(&self) -> Result<Output, Error> {
+        Bus::System.services()
+    }
+
+    fn mask(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.mask(name)
+    }
+
+    fn unmask(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.unmask(name)
+    }
+
+    fn is_masked(&self, name: &str) -> Result<Output, Error> {
+        Bus::System.is_masked(name)
+    }
+
+    fn daemon_reload(&self) -> Result<Output, Error> {
+        Bus::System.daemon_reload()
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn InlineProvider>, Error> {
+        Ok(scoped(scope, self.job_timeout))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+impl InlineProvider for SystemdUser {
+    fn is_running(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).is_running(name)
+    }
+
+    fn is_enabled(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).is_enabled(name)
+    }
+
+    fn enable(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).enable(name)
+    }
+
+    fn disable(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).disable(name)
+    }
+
+    fn start(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).start(name, self.job_timeout)
+    }
+
+    fn stop(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).stop(name, self.job_timeout)
+    }
+
+    fn reload(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).reload(name, self.job_timeout)
+    }
+
+    fn restart(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).restart(name, self.job_timeout)
+    }
+
+    fn property(&self, name: &str, property: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).property(name, property)
+    }
+
+    fn services(&self) -> Result<Output, Error> {
+        Bus::User(self.uid).services()
+    }
+
+    fn mask(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).mask(name)
+    }
+
+    fn unmask(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).unmask(name)
+    }
+
+    fn is_masked(&self, name: &str) -> Result<Output, Error> {
+        Bus::User(self.uid).is_masked(name)
+    }
+
+    fn daemon_reload(&self) -> Result<Output, Error> {
+        Bus::User(self.uid).daemon_reload()
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn InlineProvider>, Error> {
+        Ok(scoped(scope, self.job_timeout))
+    }
+
+    fn box_clone(&self) -> Box<dyn InlineProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+fn scoped(scope: Scope, job_timeout: Duration) -> Box<dyn InlineProvider> {
+    match scope {
+        Scope::System => Box::new(Systemd { job_timeout }),
+        Scope::User(uid) => Box::new(SystemdUser { uid, job_timeout }),
+    }
+}
+
+// Waits for the JobRemoved signal of `job`, given as (job path, result)
+// among None for anything else the bus delivers, and returns its result.
+pub fn wait_for_job<I>(
+    removed: I,
+    job: &str,
+    unit: &str,
+    timeout: Duration,
+) -> Result<String, service::error::Error>
+where
+    I: Iterator<Item = Option<(String, String)>>,
+{
+    let deadline = Instant::now() + timeout;
+    for item in removed {
+        if Instant::now() >= deadline {
+            break;
+        }
+        match item {
+            Some((path, result)) if path == job => return Ok(result),
+            _ => continue,
+        }
+    }
+
+    Err(service::error::Error::JobWaitTimeout {
+        unit: unit.to_string(),
+        timeout,
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Bus {
+    System,
+    User(u32),
+}
+
+impl Bus {
+    // The session bus is only ours when running as that user; otherwise
+    // the user's bus socket is opened directly.
+    fn connect(&self) -> Result<Connection, Error> {
+        match *self {
+            Bus::System => Ok(Connection::get_private(BusType::System)?),
+            Bus::User(uid) if getuid().as_raw() == uid => {
+                Ok(Connection::get_private(BusType::Session)?)
+            }
+            Bus::User(uid) => {
+                let c = Connection::open_private(&format!("unix:path=/run/user/{}/bus", uid))?;
+                c.register()?;
+                Ok(c)
+            }
+        }
+    }
+
     fn is_running(&self, name: &str) -> Result<Output, Error> {
         let state = self.get_active_state(name)?;
         Ok(Output::Bool(state == "active"))
@@ -32,174 +258,256 @@ impl InlineProvider for Systemd {
         Ok(Output::Bool(s))
     }
 
-    fn start(&self, name: &str) -> Result<Output, Error> {
-        let s = self.start_unit(name)?;
+    fn start(&self, name: &str, timeout: Duration) -> Result<Output, Error> {
+        let s = self.start_unit(name, timeout)?;
         Ok(Output::Bool(s))
     }
 
-    fn stop(&self, name: &str) -> Result<Output, Error> {
-        let s = self.stop_unit(name)?;
+    fn stop(&self, name: &str, timeout: Duration) -> Result<Output, Error> {
+        let s = self.stop_unit(name, timeout)?;
         Ok(Output::Bool(s))
     }
 
-    fn reload(&self, name: &str) -> Result<Output, Error> {
-        let s = self.reload_unit(name)?;
+    fn reload(&self, name: &str, timeout: Duration) -> Result<Output, Error> {
+        let s = self.reload_unit(name, timeout)?;
         Ok(Output::Bool(s))
     }
 
-    fn restart(&self, name: &str) -> Result<Output, Error> {
-        let s = self.restart_unit(name)?;
+    fn restart(&self, name: &str, timeout: Duration) -> Result<Output, Error> {
+        let s = self.restart_unit(name, timeout)?;
         Ok(Output::Bool(s))
     }
 
-    fn box_clone(&self) -> Box<dyn InlineProvider> {
-        Box::new((*self).clone())
+    fn property(&self, name: &str, property: &str) -> Result<Output, Error> {
+        let properties = self.get_properties(name)?;
+        let value = match properties.get(property) {
+            Some(v) => render_property(property, &*v.0),
+            None => String::new(),
+        };
+        Ok(Output::Text(value))
     }
-}
 
-impl Systemd {
-    fn start_unit(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
-        let _ = c.add_match("interface='org.freedesktop.systemd1.Manager'");
+    // Like systemctl, masking and unmasking reload the manager so that the
+    // change takes effect right away.
+    fn mask(&self, name: &str) -> Result<Output, Error> {
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
-        }
+        let m = Message::new_method_call(
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+            "MaskUnitFiles",
+        )?
+        .append3(vec![unit_name(name)], false, false);
+
+        c.send_with_reply_and_block(m, 2000)?;
+        self.daemon_reload()
+    }
+
+    fn unmask(&self, name: &str) -> Result<Output, Error> {
+        let c = self.connect()?;
 
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
-            "StartUnit",
+            "UnmaskUnitFiles",
         )?
-        .append2(service.clone(), "replace");
+        .append2(vec![unit_name(name)], false);
 
-        let _ = c.send(m);
-        self.wait_service_job_finished(c, &service, "active")
+        c.send_with_reply_and_block(m, 2000)?;
+        self.daemon_reload()
     }
 
-    fn reload_unit(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
-        let _ = c.add_match("interface='org.freedesktop.systemd1.Manager'");
+    fn is_masked(&self, name: &str) -> Result<Output, Error> {
+        let state = self.get_unit_file_state(name)?;
+        Ok(Output::Bool(state.starts_with("masked")))
+    }
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
-        }
+    // Reload blocks until the manager has reloaded, which can take longer
+    // than the usual call timeout.
+    fn daemon_reload(&self) -> Result<Output, Error> {
+        let c = self.connect()?;
 
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
-            "ReloadUnit",
-        )?
-        .append2(service.clone(), "replace");
+            "Reload",
+        )?;
 
-        let _ = c.send(m);
-        self.wait_service_job_finished(c, &service, "active")
+        c.send_with_reply_and_block(m, 30000)?;
+        Ok(Output::Bool(true))
     }
 
-    fn restart_unit(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
-        let _ = c.add_match("interface='org.freedesktop.systemd1.Manager'");
+    // Loaded service units and installed service unit files, leaving out
+    // templates which are not services by themselves.
+    fn services(&self) -> Result<Output, Error> {
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
+        let m = Message::new_method_call(
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+            "ListUnits",
+        )?;
+        let r = c.send_with_reply_and_block(m, 2000)?;
+        type Unit<'a> = (
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            Path<'a>,
+            u32,
+            &'a str,
+            Path<'a>,
+        );
+        let units: Vec<Unit> = r.read1()?;
+
+        let m = Message::new_method_call(
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+            "ListUnitFiles",
+        )?;
+        let r = c.send_with_reply_and_block(m, 2000)?;
+        let unit_files: Vec<(&str, &str)> = r.read1()?;
+
+        let mut services: BTreeMap<String, (bool, bool)> = BTreeMap::new();
+        for u in units.iter().filter(|u| u.0.ends_with(".service")) {
+            services.entry(u.0.to_string()).or_default().0 = u.3 == "active";
         }
+        for (path, state) in unit_files {
+            let name = path.rsplit('/').next().unwrap_or(path);
+            if name.ends_with(".service") && !name.ends_with("@.service") {
+                services.entry(name.to_string()).or_default().1 = state == "enabled";
+            }
+        }
+
+        let list = services
+            .into_iter()
+            .map(|(name, (running, enabled))| service::list_entry(&name, running, enabled))
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn start_unit(&self, name: &str, timeout: Duration) -> Result<bool, Error> {
+        let c = self.connect()?;
+        let unit = unit_name(name);
+        let job = self.queue_job(&c, "StartUnit", &unit)?;
+        self.wait_service_job_finished(c, &job, &unit, "active", timeout)
+    }
 
+    fn reload_unit(&self, name: &str, timeout: Duration) -> Result<bool, Error> {
+        let c = self.connect()?;
+        let unit = unit_name(name);
+        let job = self.queue_job(&c, "ReloadUnit", &unit)?;
+        self.wait_service_job_finished(c, &job, &unit, "active", timeout)
+    }
+
+    fn restart_unit(&self, name: &str, timeout: Duration) -> Result<bool, Error> {
+        let c = self.connect()?;
+        let unit = unit_name(name);
+        let job = self.queue_job(&c, "RestartUnit", &unit)?;
+        self.wait_service_job_finished(c, &job, &unit, "active", timeout)
+    }
+
+    // Subscribes to job signals before queueing the job so that its
+    // JobRemoved cannot be missed.
+    fn queue_job(&self, c: &Connection, method: &str, unit: &str) -> Result<Path<'static>, Error> {
+        c.add_match("interface='org.freedesktop.systemd1.Manager',member='JobRemoved'")?;
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
-            "RestartUnit",
+            "Subscribe",
+        )?;
+        c.send_with_reply_and_block(m, 2000)?;
+
+        let m = Message::new_method_call(
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+            method,
         )?
-        .append2(service.clone(), "replace");
+        .append2(unit, "replace");
 
-        let _ = c.send(m);
-        self.wait_service_job_finished(c, &service, "active")
+        let r = c.send_with_reply_and_block(m, 2000)?;
+        let job: Path = r.read1()?;
+        Ok(job.into_static())
     }
 
     fn wait_service_job_finished(
         &self,
         c: Connection,
-        service: &str,
+        job: &Path,
+        unit: &str,
         state: &str,
+        timeout: Duration,
     ) -> Result<bool, Error> {
-        for ci in c.iter(10) {
-            let m = if let &ConnectionItem::Signal(ref s) = &ci {
-                s
-            } else {
-                continue;
-            };
-
-            if &*m.interface().unwrap() != "org.freedesktop.systemd1.Manager" {
-                continue;
-            };
-
-            if &*m.member().unwrap() != "JobRemoved" {
-                continue;
-            };
-
-            let (_, _, unit, result) = m.get4::<u32, Path, &str, &str>();
-
-            if unit.unwrap() != service {
-                continue;
-            };
-
-            if result.unwrap() != "done" {
-                continue;
-            };
-
-            break;
+        let removed = c.iter(100).map(|ci| match ci {
+            ConnectionItem::Signal(ref m) if m.member().as_deref() == Some("JobRemoved") => {
+                let (_, path, _, result) = m.get4::<u32, Path, &str, &str>();
+                Some((
+                    path.map(|p| p.to_string()).unwrap_or_default(),
+                    result.unwrap_or("").to_string(),
+                ))
+            }
+            _ => None,
+        });
+
+        match wait_for_job(removed, job, unit, timeout)?.as_str() {
+            "done" | "skipped" => (),
+            r => {
+                let e = service::error::JobError {
+                    unit: unit.to_string(),
+                    result: r.into(),
+                    journal: self.journal_lines(unit),
+                };
+                return Err(service::error::Error::Job(e).into());
+            }
         }
 
-        if self.get_active_state(service)? == state {
+        if self.get_active_state(unit)? == state {
             Ok(true)
         } else {
             Ok(false)
         }
     }
 
-    fn stop_unit(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
-        let _ = c.add_match("interface='org.freedesktop.systemd1.Manager'");
-
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
+    // The last lines the unit logged, best effort.
+    fn journal_lines(&self, unit: &str) -> Vec<String> {
+        let mut c = process::Command::new("journalctl");
+        match *self {
+            Bus::System => c.arg(format!("_SYSTEMD_UNIT={}", unit)),
+            Bus::User(uid) => c
+                .arg(format!("_UID={}", uid))
+                .arg(format!("_SYSTEMD_USER_UNIT={}", unit)),
+        };
+        c.args(["-n", "10", "-o", "cat", "--no-pager"]);
+
+        match c.output() {
+            Ok(o) => String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .map(|l| l.to_string())
+                .collect(),
+            Err(_) => Vec::new(),
         }
+    }
 
-        let m = Message::new_method_call(
-            "org.freedesktop.systemd1",
-            "/org/freedesktop/systemd1",
-            "org.freedesktop.systemd1.Manager",
-            "StopUnit",
-        )?
-        .append2(service.clone(), "replace");
-
-        let _ = c.send(m);
-        self.wait_service_job_finished(c, &service, "inactive")
+    fn stop_unit(&self, name: &str, timeout: Duration) -> Result<bool, Error> {
+        let c = self.connect()?;
+        let unit = unit_name(name);
+        let job = self.queue_job(&c, "StopUnit", &unit)?;
+        self.wait_service_job_finished(c, &job, &unit, "inactive", timeout)
     }
 
     fn enable_unit_file_state(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
-        }
+        let unit = unit_name(name);
 
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
@@ -207,7 +515,7 @@ impl Systemd {
             "org.freedesktop.systemd1.Manager",
             "EnableUnitFiles",
         )?
-        .append3(vec![service], false, false);
+        .append3(vec![unit], false, false);
 
         c.send_with_reply_and_block(m, 2000)?;
 
@@ -215,14 +523,9 @@ impl Systemd {
     }
 
     fn disable_unit_file_state(&self, name: &str) -> Result<bool, Error> {
-        let c = Connection::get_private(BusType::System)?;
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
-        }
+        let unit = unit_name(name);
 
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
@@ -230,7 +533,7 @@ impl Systemd {
             "org.freedesktop.systemd1.Manager",
             "DisableUnitFiles",
         )?
-        .append2(vec![service], false);
+        .append2(vec![unit], false);
 
         c.send_with_reply_and_block(m, 2000)?;
 
@@ -238,7 +541,7 @@ impl Systemd {
     }
 
     fn get_active_state(&self, name: &str) -> Result<String, Error> {
-        let c = Connection::get_private(BusType::System)?;
+        let c = self.connect()?;
 
         let object_path = self.get_object_path(name)?;
 
@@ -257,38 +560,60 @@ impl Systemd {
         Ok(active_state.to_string())
     }
 
-    fn get_unit_file_state(&self, name: &str) -> Result<String, Error> {
-        let c = Connection::get_private(BusType::System)?;
+    // Properties of both the Unit interface and the one for its type, such
+    // as org.freedesktop.systemd1.Timer for timers.
+    fn get_properties(&self, name: &str) -> Result<PropMap, Error> {
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
+        let object_path = self.get_object_path(name)?;
+        let unit = unit_name(name);
+        let (_, unit_type) = unit.rsplit_once('.').unwrap_or((&unit, "service"));
+        let type_interface = format!(
+            "org.freedesktop.systemd1.{}{}",
+            unit_type[..1].to_uppercase(),
+            &unit_type[1..]
+        );
+
+        let mut properties = PropMap::new();
+        for interface in ["org.freedesktop.systemd1.Unit", &type_interface] {
+            let m = Message::new_method_call(
+                "org.freedesktop.systemd1",
+                object_path.clone(),
+                "org.freedesktop.DBus.Properties",
+                "GetAll",
+            )?
+            .append1(interface);
+
+            let r = c.send_with_reply_and_block(m, 2000)?;
+            let p: PropMap = r.read1()?;
+            properties.extend(p);
         }
 
+        Ok(properties)
+    }
+
+    fn get_unit_file_state(&self, name: &str) -> Result<String, Error> {
+        let c = self.connect()?;
+
+        let unit = unit_name(name);
+
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
             "GetUnitFileState",
         )?
-        .append1(service);
+        .append1(unit);
 
         let r = c.send_with_reply_and_block(m, 2000)?;
         let unit_file_state: &str = r.read1()?;
         Ok(unit_file_state.to_string())
     }
 
-    fn get_object_path(&self, name: &str) -> Result<Path, Error> {
-        let c = Connection::get_private(BusType::System)?;
+    fn get_object_path(&self, name: &str) -> Result<Path<'_>, Error> {
+        let c = self.connect()?;
 
-        let service: String;
-        if name.ends_with(".service") {
-            service = name.to_string()
-        } else {
-            service = name.to_string() + ".service"
-        }
+        let unit = unit_name(name);
 
         let m = Message::new_method_call(
             "org.freedesktop.systemd1",
@@ -296,10 +621,91 @@ impl Systemd {
             "org.freedesktop.systemd1.Manager",
             "GetUnit",
         )?
-        .append1(service);
+        .append1(unit);
 
         let r = c.send_with_reply_and_block(m, 2000)?;
         let object_path: Path = r.read1()?;
         Ok(object_path)
     }
 }
+
+const UNIT_TYPES: &[&str] = &[
+    "service",
+    "socket",
+    "device",
+    "mount",
+    "automount",
+    "swap",
+    "target",
+    "path",
+    "timer",
+    "slice",
+    "scope",
+];
+
+// Unit names without a unit type suffix are taken to be services.
+pub fn unit_name(name: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((_, t)) if UNIT_TYPES.contains(&t) => name.to_string(),
+        _ => format!("{}.service", name),
+    }
+}
+
+// Renders a property value the way `systemctl show` prints it: booleans as
+// yes/no, timestamps as microseconds, lists separated by spaces and one
+// line per Exec* command or Listen address.
+fn render_property(property: &str, v: &dyn RefArg) -> String {
+    match v.arg_type() {
+        ArgType::Boolean => match v.as_u64() {
+            Some(1) => "yes".to_string(),
+            _ => "no".to_string(),
+        },
+        ArgType::String | ArgType::ObjectPath | ArgType::Signature => {
+            v.as_str().unwrap_or("").to_string()
+        }
+        ArgType::Array => {
+            let items: Vec<&dyn RefArg> = match v.as_iter() {
+                Some(i) => i.collect(),
+                None => return String::new(),
+            };
+            if items.iter().any(|i| i.arg_type() == ArgType::Struct) {
+                return items
+                    .into_iter()
+                    .filter_map(|i| render_struct(property, i))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+            }
+            items
+                .into_iter()
+                .map(|i| {
+                    let s = render_property(property, i);
+                    if s.contains(char::is_whitespace) {
+                        format!("\"{}\"", s)
+                    } else {
+                        s
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+        _ => match v.as_u64() {
+            Some(u) => u.to_string(),
+            None => v.as_i64().map(|i| i.to_string()).unwrap_or_default(),
+        },
+    }
+}
+
+// Exec* entries are (path, argv, ignore_errors, ...) structs and Listen
+// entries (type, address) pairs.
+fn render_struct(property: &str, v: &dyn RefArg) -> Option<String> {
+    let mut fields = v.as_iter()?;
+    if property == "Listen" {
+        let listen_type = fields.next()?.as_str()?;
+        let address = fields.next()?.as_str()?;
+        return Some(format!("{} ({})", address, listen_type));
+    }
+
+    let argv = fields.nth(1)?;
+    let argv: Vec<&str> = argv.as_iter()?.filter_map(|a| a.as_str()).collect();
+    Some(argv.join(" "))
+}
@@ -2,6 +2,23 @@ use crate::provider::service::inline::InlineProvider;
 use crate::provider::service::shell::ShellProvider;
 use crate::provider::HandleFunc;
 
+// Which service manager a service belongs to: the system one or the one
+// running for a user's session.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scope {
+    System,
+    User(u32),
+}
+
+// Which log entries Service::logs asks for. Files only matter to init
+// systems without a journal, which otherwise look in their usual place.
+#[derive(Clone, Copy, Debug)]
+pub struct LogQuery {
+    pub since: Option<&'static str>,
+    pub lines: u32,
+    pub files: Option<&'static [&'static str]>,
+}
+
 pub struct ServiceProvider {
     pub inline: Box<dyn InlineProvider>,
     pub shell: Box<dyn ShellProvider>,
@@ -15,79 +32,153 @@ impl ServiceProvider {
         }
     }
 
-    pub fn is_running(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn is_running(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.is_running(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.is_running(name, b)),
+        })
+    }
+
+    pub fn is_enabled(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.is_enabled(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.is_enabled(name, b)),
+        })
+    }
+
+    pub fn enable(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.enable(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.enable(name, b)),
+        })
+    }
+
+    pub fn disable(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.disable(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.disable(name, b)),
+        })
+    }
+
+    pub fn start(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.start(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.start(name, b)),
+        })
+    }
+
+    pub fn stop(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.stop(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.stop(name, b)),
+        })
+    }
+
+    pub fn reload(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
+        let i = self.inline.clone();
+        let s = self.shell.clone();
+        Box::new(HandleFunc {
+            inline: Box::new(move || i.scoped(scope)?.reload(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.reload(name, b)),
+        })
+    }
+
+    pub fn restart(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.is_running(name)),
-            shell: Box::new(move |b| s.is_running(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.restart(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.restart(name, b)),
         })
     }
 
-    pub fn is_enabled(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn property(
+        &self,
+        name: &'static str,
+        property: &'static str,
+        scope: Scope,
+    ) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.is_enabled(name)),
-            shell: Box::new(move |b| s.is_enabled(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.property(name, property)),
+            shell: Box::new(move |b| s.scoped(scope)?.property(name, property, b)),
         })
     }
 
-    pub fn enable(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn logs(&self, name: &'static str, query: LogQuery, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.enable(name)),
-            shell: Box::new(move |b| s.enable(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.logs(name, &query)),
+            shell: Box::new(move |b| s.scoped(scope)?.logs(name, &query, b)),
         })
     }
 
-    pub fn disable(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn services(&self) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.disable(name)),
-            shell: Box::new(move |b| s.disable(name, b)),
+            inline: Box::new(move || i.services()),
+            shell: Box::new(move |b| s.services(b)),
         })
     }
 
-    pub fn start(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn mask(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.start(name)),
-            shell: Box::new(move |b| s.start(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.mask(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.mask(name, b)),
         })
     }
 
-    pub fn stop(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn unmask(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.stop(name)),
-            shell: Box::new(move |b| s.stop(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.unmask(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.unmask(name, b)),
         })
     }
 
-    pub fn reload(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn is_masked(&self, name: &'static str, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.reload(name)),
-            shell: Box::new(move |b| s.reload(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.is_masked(name)),
+            shell: Box::new(move |b| s.scoped(scope)?.is_masked(name, b)),
         })
     }
 
-    pub fn restart(&self, name: &'static str) -> Box<HandleFunc> {
+    pub fn daemon_reload(&self, scope: Scope) -> Box<HandleFunc> {
         let i = self.inline.clone();
         let s = self.shell.clone();
         Box::new(HandleFunc {
-            inline: Box::new(move || i.restart(name)),
-            shell: Box::new(move |b| s.restart(name, b)),
+            inline: Box::new(move || i.scoped(scope)?.daemon_reload()),
+            shell: Box::new(move |b| s.scoped(scope)?.daemon_reload(b)),
         })
     }
 }
 
+// Service listings are passed around as Output::List of tab separated
+// name, running and enabled state.
+pub fn list_entry(name: &str, running: bool, enabled: bool) -> String {
+    format!("{}\t{}\t{}", name, running, enabled)
+}
+
 pub mod error;
 pub mod inline;
 pub mod shell;
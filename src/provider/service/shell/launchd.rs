@@ -0,0 +1,111 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::service::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+// Services are launchd labels in the system domain, with their job
+// definitions in /Library/LaunchDaemons.
+#[derive(Clone, Debug)]
+pub struct Launchd;
+
+impl Launchd {
+    fn is_loaded(&self, name: &str, b: &dyn Backend) -> bool {
+        let c = Command::new(&format!("launchctl print system/{}", name));
+        match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        }
+    }
+
+    // `launchctl print-disabled` lists overrides as "label" => disabled, or
+    // "label" => true on older releases.
+    fn is_disabled(&self, name: &str, b: &dyn Backend) -> Result<bool, Error> {
+        let c = Command::new("launchctl print-disabled system");
+        let res = b.run_command(c)?;
+        let label = format!("\"{}\"", name);
+
+        let disabled = res.stdout.lines().any(|line| match line.split_once("=>") {
+            Some((l, v)) => l.trim() == label && matches!(v.trim(), "disabled" | "true"),
+            None => false,
+        });
+        Ok(disabled)
+    }
+}
+
+impl ShellProvider for Launchd {
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("launchctl print system/{}", name));
+        let running = match b.run_command(c) {
+            Ok(r) => r.stdout.lines().any(|l| l.trim() == "state = running"),
+            Err(_) => false,
+        };
+        Ok(Output::Bool(running))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let enabled = !self.is_disabled(name, b)? && self.is_loaded(name, b);
+        Ok(Output::Bool(enabled))
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("launchctl enable system/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("launchctl disable system/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new(&format!("launchctl print system/{} > /dev/null 2>&1", name));
+        c.or(&format!(
+            "launchctl bootstrap system /Library/LaunchDaemons/{}.plist",
+            name
+        ));
+        c.and(&format!("launchctl kickstart system/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("launchctl bootout system/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("launchctl kickstart -k system/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    // launchd has no reload of its own, so a reload restarts the job.
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.restart(name, b)
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
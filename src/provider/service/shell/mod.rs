@@ -1,6 +1,9 @@
+use crate::backend::command::Command;
 use crate::backend::Backend;
 use crate::provider::error::Error;
 use crate::provider::error::HandleFuncNotDefined;
+use crate::provider::service::LogQuery;
+use crate::provider::service::Scope;
 use crate::provider::Output;
 
 use std::fmt::Debug;
@@ -70,6 +73,75 @@ pub trait ShellProvider: Debug {
         Err(e.into())
     }
 
+    fn property(&self, _: &str, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "property".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn logs(&self, _: &str, _: &LogQuery, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "logs".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn services(&self, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "services".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn mask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "mask".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn unmask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "unmask".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn is_masked(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "is_masked".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn daemon_reload(&self, _: &dyn Backend) -> Result<Output, Error> {
+        let e = HandleFuncNotDefined {
+            provider: format!("{:?}", self),
+            func: "daemon_reload".to_string(),
+        };
+        Err(e.into())
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn ShellProvider>, Error> {
+        match scope {
+            Scope::System => Ok(self.box_clone()),
+            Scope::User(_) => {
+                let e = HandleFuncNotDefined {
+                    provider: format!("{:?}", self),
+                    func: "scoped".to_string(),
+                };
+                Err(e.into())
+            }
+        }
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider>;
 }
 
@@ -79,7 +151,57 @@ impl Clone for Box<dyn ShellProvider> {
     }
 }
 
+// The last lines of log files, as journal export records with only a
+// MESSAGE field. tail counts lines per file, so the combined output is cut
+// down to `lines`.
+pub fn tail_logs(files: &[String], lines: u32, b: &dyn Backend) -> Result<Output, Error> {
+    let files: Vec<String> = files.iter().map(|f| quote(f)).collect();
+    let c = Command::new(&format!("tail -q -n {} {}", lines, files.join(" ")));
+    let res = b.run_command(c)?;
+
+    let all: Vec<&str> = res.stdout.lines().collect();
+    let skip = all.len().saturating_sub(lines as usize);
+    let records = all[skip..]
+        .iter()
+        .map(|l| serde_json::json!({ "MESSAGE": l }).to_string())
+        .collect();
+    Ok(Output::List(records))
+}
+
+// Wraps a string in single quotes for the shell, so that a quote inside it
+// can't end the argument.
+pub fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+// Running state by name from `service --status-all`, which prints
+// " [ + ]  ssh" on Debian and "sshd (pid  1700) is running..." on Red Hat.
+// The state of Debian's " [ ? ]" entries is unknown.
+pub fn parse_status_all(stdout: &str) -> Vec<(String, Option<bool>)> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("[ ") {
+                let (state, name) = rest.split_once(" ]")?;
+                let running = match state {
+                    "+" => Some(true),
+                    "-" => Some(false),
+                    _ => None,
+                };
+                return Some((name.trim().to_string(), running));
+            }
+            let name = line.split_whitespace().next()?.trim_end_matches(':');
+            Some((name.to_string(), Some(line.contains("is running"))))
+        })
+        .collect()
+}
+
+pub mod launchd;
 pub mod null;
+pub mod openrc;
+pub mod runit;
+pub mod s6;
 pub mod systemd;
 pub mod sysvinit;
 pub mod ubuntu_init;
@@ -0,0 +1,122 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::service;
+use crate::provider::service::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::collections::BTreeMap;
+use std::result::Result;
+
+// Services are enabled by adding them to the default runlevel.
+#[derive(Clone, Debug)]
+pub struct OpenRc;
+
+impl ShellProvider for OpenRc {
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-service {} status", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("test -e /etc/runlevels/default/{}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-update add {} default", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-update del {} default", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-service {} start", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-service {} stop", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-service {} reload", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rc-service {} restart", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    // `rc-status --all` lists services under "Runlevel: default" and the
+    // like, with " sshd  [  started  ]" lines. As for `is_enabled`, services
+    // in the default runlevel are enabled; the boot and sysinit runlevels and
+    // dynamic ones, holding those started by hand or as dependencies, don't
+    // count.
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let res = b.run_command(Command::new("rc-status --all"))?;
+
+        let mut services: BTreeMap<String, (bool, bool)> = BTreeMap::new();
+        let mut in_default = false;
+        for line in res.stdout.lines() {
+            if !line.starts_with(' ') {
+                in_default = line.trim_end() == "Runlevel: default";
+                continue;
+            }
+            let (name, state) = match line.trim().split_once(' ') {
+                Some((n, s)) => (n, s),
+                None => continue,
+            };
+            let e = services.entry(name.to_string()).or_default();
+            e.0 = state.contains("started");
+            e.1 |= in_default;
+        }
+
+        let list = services
+            .into_iter()
+            .map(|(name, (running, enabled))| service::list_entry(&name, running, enabled))
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
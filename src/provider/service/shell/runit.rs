@@ -0,0 +1,108 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::service::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+// Service definitions live in /etc/sv and are enabled by linking them into
+// the directory runsvdir supervises, /etc/service on most distributions and
+// /var/service on Void.
+#[derive(Clone, Debug)]
+pub struct Runit {
+    pub service_dir: &'static str,
+}
+
+impl Default for Runit {
+    fn default() -> Runit {
+        Runit {
+            service_dir: "/etc/service",
+        }
+    }
+}
+
+impl Runit {
+    fn path(&self, name: &str) -> String {
+        format!("{}/{}", self.service_dir, name)
+    }
+}
+
+impl ShellProvider for Runit {
+    // `sv status` prints "run: ..." for a service that is up.
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("sv status {}", self.path(name)));
+        let running = match b.run_command(c) {
+            Ok(r) => r.success && r.stdout.starts_with("run:"),
+            Err(_) => false,
+        };
+        Ok(Output::Bool(running))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("test -e {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("ln -s /etc/sv/{} {}", name, self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("rm -f {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("sv start {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("sv stop {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("sv reload {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("sv restart {}", self.path(name)));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
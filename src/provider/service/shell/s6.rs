@@ -0,0 +1,92 @@
+use crate::backend::command::Command;
+use crate::backend::Backend;
+use crate::provider::error::Error;
+use crate::provider::service::shell::ShellProvider;
+use crate::provider::Output;
+
+use std::result::Result;
+
+const SCAN_DIR: &str = "/run/service";
+
+// Services are s6-rc longruns supervised from /run/service. Enabling one
+// adds it to the default bundle of the compiled database.
+#[derive(Clone, Debug)]
+pub struct S6;
+
+impl ShellProvider for S6 {
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-svstat -o up {}/{}", SCAN_DIR, name));
+        let running = match b.run_command(c) {
+            Ok(r) => r.success && r.stdout == "true",
+            Err(_) => false,
+        };
+        Ok(Output::Bool(running))
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new("s6-rc-db contents default");
+        let enabled = match b.run_command(c) {
+            Ok(r) => r.success && r.stdout.lines().any(|l| l.trim() == name),
+            Err(_) => false,
+        };
+        Ok(Output::Bool(enabled))
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-rc-bundle-update add default {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-rc-bundle-update delete default {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-rc -u change {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-rc -d change {}", name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-svc -h {}/{}", SCAN_DIR, name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = Command::new(&format!("s6-svc -r {}/{}", SCAN_DIR, name));
+        let success = match b.run_command(c) {
+            Ok(r) => r.success,
+            Err(_) => false,
+        };
+        Ok(Output::Bool(success))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
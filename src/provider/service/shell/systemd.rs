@@ -1,17 +1,195 @@
+use crate::backend;
 use crate::backend::command::Command;
 use crate::backend::Backend;
 use crate::provider::error::Error;
+use crate::provider::error::StringError;
+use crate::provider::service;
+use crate::provider::service::error::{JobError, JobResult};
+use crate::provider::service::inline::systemd;
 use crate::provider::service::shell::ShellProvider;
+use crate::provider::service::LogQuery;
+use crate::provider::service::Scope;
 use crate::provider::Output;
 
+use std::collections::BTreeMap;
 use std::result::Result;
 
 #[derive(Clone, Debug)]
 pub struct Systemd;
 
+// Units of a user's service manager, managed with `systemctl --user` in a
+// login session of that user.
+#[derive(Clone, Debug)]
+pub struct SystemdUser {
+    pub uid: u32,
+}
+
 impl ShellProvider for Systemd {
     fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl is-active {}", name));
+        Manager::System.is_running(name, b)
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.is_enabled(name, b)
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.enable(name, b)
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.disable(name, b)
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.start(name, b)
+    }
+
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.reload(name, b)
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.restart(name, b)
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.stop(name, b)
+    }
+
+    fn property(&self, name: &str, property: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.property(name, property, b)
+    }
+
+    fn logs(&self, name: &str, query: &LogQuery, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.logs(name, query, b)
+    }
+
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.services(b)
+    }
+
+    fn mask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.mask(name, b)
+    }
+
+    fn unmask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.unmask(name, b)
+    }
+
+    fn is_masked(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.is_masked(name, b)
+    }
+
+    fn daemon_reload(&self, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::System.daemon_reload(b)
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn ShellProvider>, Error> {
+        Ok(scoped(scope))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+impl ShellProvider for SystemdUser {
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).is_running(name, b)
+    }
+
+    fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).is_enabled(name, b)
+    }
+
+    fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).enable(name, b)
+    }
+
+    fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).disable(name, b)
+    }
+
+    fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).start(name, b)
+    }
+
+    fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).reload(name, b)
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).restart(name, b)
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).stop(name, b)
+    }
+
+    fn property(&self, name: &str, property: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).property(name, property, b)
+    }
+
+    fn logs(&self, name: &str, query: &LogQuery, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).logs(name, query, b)
+    }
+
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).services(b)
+    }
+
+    fn mask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).mask(name, b)
+    }
+
+    fn unmask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).unmask(name, b)
+    }
+
+    fn is_masked(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).is_masked(name, b)
+    }
+
+    fn daemon_reload(&self, b: &dyn Backend) -> Result<Output, Error> {
+        Manager::User(self.uid).daemon_reload(b)
+    }
+
+    fn scoped(&self, scope: Scope) -> Result<Box<dyn ShellProvider>, Error> {
+        Ok(scoped(scope))
+    }
+
+    fn box_clone(&self) -> Box<dyn ShellProvider> {
+        Box::new((*self).clone())
+    }
+}
+
+fn scoped(scope: Scope) -> Box<dyn ShellProvider> {
+    match scope {
+        Scope::System => Box::new(Systemd),
+        Scope::User(uid) => Box::new(SystemdUser { uid }),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Manager {
+    System,
+    User(u32),
+}
+
+impl Manager {
+    fn systemctl(&self, args: &str) -> Command {
+        match *self {
+            Manager::System => Command::new(&format!("systemctl {}", args)),
+            Manager::User(uid) => Command::new(&format!(
+                "machinectl shell -q --uid={} .host /usr/bin/env systemctl --user {}",
+                uid, args
+            )),
+        }
+    }
+
+    fn is_running(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl(&format!("is-active {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -20,7 +198,7 @@ impl ShellProvider for Systemd {
     }
 
     fn is_enabled(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl is-enabled {}", name));
+        let c = self.systemctl(&format!("is-enabled {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -29,7 +207,7 @@ impl ShellProvider for Systemd {
     }
 
     fn enable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl enable {}", name));
+        let c = self.systemctl(&format!("enable {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -38,7 +216,7 @@ impl ShellProvider for Systemd {
     }
 
     fn disable(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl disable {}", name));
+        let c = self.systemctl(&format!("disable {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -47,16 +225,64 @@ impl ShellProvider for Systemd {
     }
 
     fn start(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl start {}", name));
-        let success = match b.run_command(c) {
-            Ok(r) => r.success,
-            Err(_) => false,
-        };
-        Ok(Output::Bool(success))
+        self.run_job("start", name, b)
     }
 
     fn reload(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl reload {}", name));
+        self.run_job("reload", name, b)
+    }
+
+    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.run_job("restart", name, b)
+    }
+
+    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        self.run_job("stop", name, b)
+    }
+
+    // systemctl waits for the job and fails when it does, explaining why
+    // on stderr, as in "Job for nginx.service failed because the control
+    // process exited with error code."
+    fn run_job(&self, verb: &str, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl(&format!("{} {}", verb, name));
+        let stderr = match b.run_command(c) {
+            Ok(r) if r.success => return Ok(Output::Bool(true)),
+            Ok(r) => r.stderr,
+            Err(backend::error::Error::Command(e)) => e.message,
+            Err(e) => return Err(e.into()),
+        };
+
+        let result = if stderr.contains("timeout") {
+            JobResult::Timeout
+        } else if stderr.contains("dependency") {
+            JobResult::Dependency
+        } else if stderr.contains("canceled") {
+            JobResult::Canceled
+        } else {
+            JobResult::Failed
+        };
+        let e = JobError {
+            unit: systemd::unit_name(name),
+            result,
+            journal: self.journal_lines(name, b),
+        };
+        Err(service::error::Error::Job(e).into())
+    }
+
+    // The last lines the unit logged, best effort.
+    fn journal_lines(&self, name: &str, b: &dyn Backend) -> Vec<String> {
+        let c = self.journalctl(&format!(
+            "-u {} -n 10 -o cat --no-pager",
+            systemd::unit_name(name)
+        ));
+        match b.run_command(c) {
+            Ok(r) => r.stdout.lines().map(|l| l.to_string()).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn mask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl(&format!("mask {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -64,8 +290,8 @@ impl ShellProvider for Systemd {
         Ok(Output::Bool(success))
     }
 
-    fn restart(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl restart {}", name));
+    fn unmask(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl(&format!("unmask {}", name));
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -73,8 +299,17 @@ impl ShellProvider for Systemd {
         Ok(Output::Bool(success))
     }
 
-    fn stop(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
-        let c = Command::new(&format!("systemctl stop {}", name));
+    // is-enabled prints "masked" or "masked-runtime" and fails for masked
+    // units.
+    fn is_masked(&self, name: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = self.systemctl(&format!("is-enabled {}", name));
+        c.or("true");
+        let res = b.run_command(c)?;
+        Ok(Output::Bool(res.stdout.starts_with("masked")))
+    }
+
+    fn daemon_reload(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl("daemon-reload");
         let success = match b.run_command(c) {
             Ok(r) => r.success,
             Err(_) => false,
@@ -82,7 +317,140 @@ impl ShellProvider for Systemd {
         Ok(Output::Bool(success))
     }
 
-    fn box_clone(&self) -> Box<dyn ShellProvider> {
-        Box::new((*self).clone())
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let c = self.systemctl("list-units --type=service --all --output=json");
+        let units: Vec<serde_json::Value> = serde_json::from_str(&b.run_command(c)?.stdout)?;
+        let c = self.systemctl("list-unit-files --type=service --output=json");
+        let unit_files: Vec<serde_json::Value> = serde_json::from_str(&b.run_command(c)?.stdout)?;
+
+        let mut services: BTreeMap<String, (bool, bool)> = BTreeMap::new();
+        for u in &units {
+            if let Some(name) = u["unit"].as_str() {
+                services.entry(name.to_string()).or_default().0 = u["active"] == "active";
+            }
+        }
+        for u in &unit_files {
+            match u["unit_file"].as_str() {
+                Some(name) if !name.ends_with("@.service") => {
+                    services.entry(name.to_string()).or_default().1 = u["state"] == "enabled";
+                }
+                _ => (),
+            }
+        }
+
+        let list = services
+            .into_iter()
+            .map(|(name, (running, enabled))| service::list_entry(&name, running, enabled))
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn journalctl(&self, args: &str) -> Command {
+        match *self {
+            Manager::System => Command::new(&format!("journalctl {}", args)),
+            Manager::User(uid) => Command::new(&format!(
+                "machinectl shell -q --uid={} .host /usr/bin/env journalctl --user {}",
+                uid, args
+            )),
+        }
+    }
+
+    fn logs(&self, name: &str, query: &LogQuery, b: &dyn Backend) -> Result<Output, Error> {
+        let since = match query.since {
+            Some(s) => format!(" --since {}", service::shell::quote(s)),
+            None => "".to_string(),
+        };
+        let c = self.journalctl(&format!(
+            "-u {} -o json --no-pager -n {}{}",
+            name, query.lines, since
+        ));
+        let res = b.run_command(c)?;
+        Ok(Output::List(
+            res.stdout.lines().map(|l| l.to_string()).collect(),
+        ))
+    }
+
+    // systemctl prints "Property=value" lines, as --value and --timestamp
+    // need newer systemd than RedHat 7 and 8 have. Timestamps are printed in
+    // UTC, with seconds only, and converted to microseconds since the epoch,
+    // which is what D-Bus reports.
+    fn property(&self, name: &str, property: &str, b: &dyn Backend) -> Result<Output, Error> {
+        let timestamp = property.ends_with("Timestamp")
+            || property.ends_with("USecRealtime")
+            || property == "LastTriggerUSec";
+        let args = format!("show -p {} {}", property, name);
+        let c = match *self {
+            Manager::System => Command::new(&format!("TZ=UTC systemctl {}", args)),
+            Manager::User(uid) => Command::new(&format!(
+                "machinectl shell -q --uid={} .host /usr/bin/env TZ=UTC systemctl --user {}",
+                uid, args
+            )),
+        };
+        let res = b.run_command(c)?;
+        let prefix = format!("{}=", property);
+        let stdout = res
+            .stdout
+            .lines()
+            .map(|l| l.strip_prefix(&prefix).unwrap_or(l))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let value = if timestamp {
+            unix_micros(&stdout)?.to_string()
+        } else if property.starts_with("Exec") {
+            exec_command_lines(&stdout)
+        } else {
+            stdout
+        };
+        Ok(Output::Text(value))
     }
 }
+
+// Parses "Mon 2026-10-19 10:00:00 UTC", as printed with TZ=UTC, or
+// "Mon 2026-10-19 10:00:00.123456 UTC" with microseconds. Unset timestamps
+// are empty or "n/a" and count as 0.
+fn unix_micros(value: &str) -> Result<u64, Error> {
+    let fields: Vec<&str> = value.split_whitespace().collect();
+    let (date, time) = match fields.as_slice() {
+        [_, date, time, "UTC"] => (*date, *time),
+        [] | ["n/a"] => return Ok(0),
+        _ => {
+            let e = StringError {
+                string: format!("Unexpected timestamp: {}", value),
+            };
+            return Err(e.into());
+        }
+    };
+
+    let mut d = date.splitn(3, '-');
+    let mut next = || d.next().unwrap_or("").parse::<i64>();
+    let (year, month, day) = (next()?, next()?, next()?);
+    let (hms, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let micros = format!("{:0<6}", fraction);
+    let mut t = hms.splitn(3, ':');
+    let mut next = || t.next().unwrap_or("").parse::<i64>();
+    let (hour, minute, second) = (next()?, next()?, next()?);
+
+    // Days since the epoch of a proleptic Gregorian date, see
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    Ok(seconds as u64 * 1_000_000 + micros.parse::<u64>()?)
+}
+
+// `systemctl show` prints Exec* entries as
+// "{ path=/usr/sbin/sshd ; argv[]=/usr/sbin/sshd -D ; ignore_errors=no ; ... }".
+fn exec_command_lines(value: &str) -> String {
+    value
+        .split("argv[]=")
+        .skip(1)
+        .map(|s| s.split(" ;").next().unwrap_or("").to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,7 +1,10 @@
 use crate::backend::command::Command;
 use crate::backend::Backend;
 use crate::provider::error::Error;
+use crate::provider::service;
+use crate::provider::service::shell;
 use crate::provider::service::shell::ShellProvider;
+use crate::provider::service::LogQuery;
 use crate::provider::Output;
 
 use std::result::Result;
@@ -84,7 +87,65 @@ impl ShellProvider for SysVInit {
         Ok(Output::Bool(success))
     }
 
+    // Log files carry no timestamps tail could filter on.
+    fn logs(&self, name: &str, query: &LogQuery, b: &dyn Backend) -> Result<Output, Error> {
+        if query.since.is_some() {
+            return Err(not_supported("logs since"));
+        }
+        let files = match query.files {
+            Some(f) => f.iter().map(|f| f.to_string()).collect(),
+            None => vec![format!("/var/log/{}.log", name)],
+        };
+        shell::tail_logs(&files, query.lines, b)
+    }
+
+    // Services chkconfig knows about, enabled when on in runlevel 3.
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let res = b.run_command(Command::new("chkconfig --list"))?;
+        let mut c = Command::new("service --status-all");
+        c.or("true");
+        let running = shell::parse_status_all(&b.run_command(c)?.stdout);
+
+        let list = res
+            .stdout
+            .lines()
+            .take_while(|l| !l.trim().is_empty())
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let name = fields.next()?;
+                let enabled = fields.any(|f| f == "3:on");
+                let running = running.iter().any(|(n, r)| n == name && *r == Some(true));
+                Some(service::list_entry(name, running, enabled))
+            })
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn mask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("mask"))
+    }
+
+    fn unmask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("unmask"))
+    }
+
+    fn is_masked(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("is_masked"))
+    }
+
+    fn daemon_reload(&self, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("daemon_reload"))
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider> {
         Box::new((*self).clone())
     }
 }
+
+fn not_supported(operation: &str) -> Error {
+    service::error::Error::NotSupported {
+        operation: operation.to_string(),
+        init: "SysVInit".to_string(),
+    }
+    .into()
+}
@@ -2,9 +2,13 @@ use crate::backend::command::Command;
 use crate::backend::command::CommandResult;
 use crate::backend::Backend;
 use crate::provider::error::Error;
+use crate::provider::service;
+use crate::provider::service::shell;
 use crate::provider::service::shell::ShellProvider;
+use crate::provider::service::LogQuery;
 use crate::provider::Output;
 
+use std::collections::BTreeMap;
 use std::result::Result;
 
 #[derive(Clone, Debug)]
@@ -109,7 +113,91 @@ impl ShellProvider for UbuntuInit {
         Ok(Output::Bool(success))
     }
 
+    // Log files carry no timestamps tail could filter on.
+    fn logs(&self, name: &str, query: &LogQuery, b: &dyn Backend) -> Result<Output, Error> {
+        if query.since.is_some() {
+            return Err(not_supported("logs since"));
+        }
+        let files = match query.files {
+            Some(f) => f.iter().map(|f| f.to_string()).collect(),
+            None => vec![format!("/var/log/upstart/{}.log", name)],
+        };
+        shell::tail_logs(&files, query.lines, b)
+    }
+
+    // SysV services with their rc3.d links plus Upstart jobs, which are
+    // enabled when they have a "start on" stanza.
+    fn services(&self, b: &dyn Backend) -> Result<Output, Error> {
+        let mut c = Command::new("service --status-all 2>&1");
+        c.or("true");
+        let sysv = shell::parse_status_all(&b.run_command(c)?.stdout);
+        let mut c = Command::new("ls /etc/rc3.d/");
+        c.or("true");
+        let rc3 = b.run_command(c)?.stdout;
+        let mut c = Command::new("initctl list");
+        c.or("true");
+        let jobs = b.run_command(c)?.stdout;
+        let mut c = Command::new("grep -l '^\\s*start on' /etc/init/*.conf");
+        c.or("true");
+        let started = b.run_command(c)?.stdout;
+
+        let mut services: BTreeMap<String, (bool, bool)> = BTreeMap::new();
+        for line in jobs.lines() {
+            let mut fields = line.split_whitespace();
+            if let (Some(name), Some(state)) = (fields.next(), fields.next()) {
+                let e = services.entry(name.to_string()).or_default();
+                e.0 = state.starts_with("start/running");
+            }
+        }
+        for path in started.lines() {
+            let job = path
+                .trim_start_matches("/etc/init/")
+                .trim_end_matches(".conf");
+            services.entry(job.to_string()).or_default().1 = true;
+        }
+        for (name, running) in sysv {
+            // Upstart jobs show up as " [ ? ]" too.
+            if running.is_none() && services.contains_key(&name) {
+                continue;
+            }
+            let enabled = rc3
+                .lines()
+                .any(|l| l.len() > 3 && l.starts_with('S') && l[3..] == name);
+            services.insert(name, (running == Some(true), enabled));
+        }
+
+        let list = services
+            .into_iter()
+            .map(|(name, (running, enabled))| service::list_entry(&name, running, enabled))
+            .collect();
+        Ok(Output::List(list))
+    }
+
+    fn mask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("mask"))
+    }
+
+    fn unmask(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("unmask"))
+    }
+
+    fn is_masked(&self, _: &str, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("is_masked"))
+    }
+
+    fn daemon_reload(&self, _: &dyn Backend) -> Result<Output, Error> {
+        Err(not_supported("daemon_reload"))
+    }
+
     fn box_clone(&self) -> Box<dyn ShellProvider> {
         Box::new((*self).clone())
     }
 }
+
+fn not_supported(operation: &str) -> Error {
+    service::error::Error::NotSupported {
+        operation: operation.to_string(),
+        init: "Upstart".to_string(),
+    }
+    .into()
+}
@@ -0,0 +1,57 @@
+use crate::backend::Backend;
+use crate::provider::bond::BondProvider;
+use crate::provider::error;
+use crate::provider::Output;
+
+pub struct Bond<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a BondProvider,
+}
+
+impl<'a> Bond<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a BondProvider) -> Bond<'a> {
+        Bond {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn exists(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.exists(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn mode(&self) -> Result<String, error::Error> {
+        self.backend
+            .handle(self.provider.mode(self.name))
+            .and_then(Output::to_string)
+    }
+
+    pub fn slaves(&self) -> Result<Vec<String>, error::Error> {
+        self.backend
+            .handle(self.provider.slaves(self.name))
+            .and_then(Output::to_vec)
+    }
+
+    pub fn active_slave(&self) -> Result<Option<String>, error::Error> {
+        let slave = self
+            .backend
+            .handle(self.provider.active_slave(self.name))
+            .and_then(Output::to_string)?;
+
+        // Only the active-backup family of modes has an active slave.
+        match slave.as_str() {
+            "" | "None" => Ok(None),
+            _ => Ok(Some(slave)),
+        }
+    }
+
+    pub fn miimon(&self) -> Result<u32, error::Error> {
+        self.backend
+            .handle(self.provider.miimon(self.name))
+            .and_then(Output::to_u32)
+    }
+}
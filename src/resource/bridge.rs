@@ -0,0 +1,38 @@
+use crate::backend::Backend;
+use crate::provider::bridge::BridgeProvider;
+use crate::provider::error;
+use crate::provider::Output;
+
+pub struct Bridge<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a BridgeProvider,
+}
+
+impl<'a> Bridge<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a BridgeProvider) -> Bridge<'a> {
+        Bridge {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn exists(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.exists(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn interfaces(&self) -> Result<Vec<String>, error::Error> {
+        self.backend
+            .handle(self.provider.interfaces(self.name))
+            .and_then(Output::to_vec)
+    }
+
+    pub fn stp_enabled(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.stp_enabled(self.name))
+            .and_then(Output::to_bool)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::backend::Backend;
+use crate::provider::cgroup::CgroupProvider;
+use crate::provider::error;
+use crate::provider::Output;
+
+pub struct Cgroup<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a CgroupProvider,
+}
+
+impl<'a> Cgroup<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a CgroupProvider) -> Cgroup<'a> {
+        Cgroup {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn value(&self, key: &'static str) -> Result<String, error::Error> {
+        self.backend
+            .handle(self.provider.value(self.name, key))
+            .and_then(Output::to_string)
+    }
+}
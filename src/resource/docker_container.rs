@@ -0,0 +1,101 @@
+use crate::backend::Backend;
+use crate::provider::docker;
+use crate::provider::docker::DockerProvider;
+use crate::provider::error;
+use crate::provider::Output;
+
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mount {
+    pub kind: String,
+    pub source: String,
+    pub destination: String,
+    pub mode: String,
+    pub rw: bool,
+}
+
+pub struct DockerContainer<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a DockerProvider,
+}
+
+impl<'a> DockerContainer<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a DockerProvider) -> DockerContainer<'a> {
+        DockerContainer {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn exists(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.container_exists(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn is_running(&self) -> Result<bool, error::Error> {
+        let v = self.inspect()?;
+        let running = docker::inspect_field(&v, "/State/Running")?;
+        Ok(running.as_bool().unwrap_or(false))
+    }
+
+    pub fn image(&self) -> Result<String, error::Error> {
+        let v = self.inspect()?;
+        docker::inspect_string(&v, "/Config/Image")
+    }
+
+    pub fn env(&self) -> Result<Vec<String>, error::Error> {
+        let v = self.inspect()?;
+        let env = match v.pointer("/Config/Env").and_then(Value::as_array) {
+            Some(a) => a
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|s| s.to_string())
+                .collect(),
+            None => Vec::new(),
+        };
+        Ok(env)
+    }
+
+    pub fn mounts(&self) -> Result<Vec<Mount>, error::Error> {
+        let v = self.inspect()?;
+        let field = |m: &Value, key: &str| m[key].as_str().unwrap_or("").to_string();
+        let mounts = match v.pointer("/Mounts").and_then(Value::as_array) {
+            Some(a) => a
+                .iter()
+                .map(|m| Mount {
+                    kind: field(m, "Type"),
+                    source: field(m, "Source"),
+                    destination: field(m, "Destination"),
+                    mode: field(m, "Mode"),
+                    rw: m["RW"].as_bool().unwrap_or(false),
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        Ok(mounts)
+    }
+
+    pub fn restart_policy(&self) -> Result<String, error::Error> {
+        let v = self.inspect()?;
+        docker::inspect_string(&v, "/HostConfig/RestartPolicy/Name")
+    }
+
+    pub fn labels(&self) -> Result<HashMap<String, String>, error::Error> {
+        let v = self.inspect()?;
+        Ok(docker::inspect_labels(&v))
+    }
+
+    fn inspect(&self) -> Result<Value, error::Error> {
+        let s = self
+            .backend
+            .handle(self.provider.inspect_container(self.name))
+            .and_then(Output::to_string)?;
+        docker::parse_inspect(&s)
+    }
+}
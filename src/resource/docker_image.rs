@@ -0,0 +1,54 @@
+use crate::backend::Backend;
+use crate::provider::docker;
+use crate::provider::docker::DockerProvider;
+use crate::provider::error;
+use crate::provider::Output;
+
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+pub struct DockerImage<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a DockerProvider,
+}
+
+impl<'a> DockerImage<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a DockerProvider) -> DockerImage<'a> {
+        DockerImage {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn exists(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.image_exists(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn id(&self) -> Result<String, error::Error> {
+        let v = self.inspect()?;
+        docker::inspect_string(&v, "/Id")
+    }
+
+    pub fn created(&self) -> Result<String, error::Error> {
+        let v = self.inspect()?;
+        docker::inspect_string(&v, "/Created")
+    }
+
+    pub fn labels(&self) -> Result<HashMap<String, String>, error::Error> {
+        let v = self.inspect()?;
+        Ok(docker::inspect_labels(&v))
+    }
+
+    fn inspect(&self) -> Result<Value, error::Error> {
+        let s = self
+            .backend
+            .handle(self.provider.inspect_image(self.name))
+            .and_then(Output::to_string)?;
+        docker::parse_inspect(&s)
+    }
+}
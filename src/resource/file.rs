@@ -91,6 +91,27 @@ impl<'a> File<'a> {
             .and_then(Output::to_string)
     }
 
+    pub fn write_contents(&self, contents: &[u8]) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.write_contents(self.name, contents))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn append(&self, contents: &[u8]) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.append(self.name, contents))
+            .and_then(Output::to_bool)
+    }
+
+    // Writes to a temporary file next to the original and renames it into
+    // place, keeping the original's mode and ownership. A symbolic link is
+    // kept and the file it points to is replaced.
+    pub fn replace(&self, contents: &[u8]) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.replace(self.name, contents))
+            .and_then(Output::to_bool)
+    }
+
     pub fn owner(&self) -> Result<String, error::Error> {
         self.backend
             .handle(self.provider.owner(self.name))
@@ -0,0 +1,44 @@
+use crate::backend::Backend;
+use crate::provider::error;
+use crate::provider::host::HostProvider;
+use crate::provider::host::{Protocol, Resolver};
+use crate::provider::Output;
+
+pub struct Host<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a HostProvider,
+}
+
+impl<'a> Host<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a HostProvider) -> Host<'a> {
+        Host {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn is_resolvable(&self, by: Resolver) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_resolvable(self.name, by))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn ipaddress(&self) -> Result<String, error::Error> {
+        self.backend
+            .handle(self.provider.ipaddress(self.name))
+            .and_then(Output::to_string)
+    }
+
+    pub fn is_reachable(
+        &self,
+        port: Option<usize>,
+        proto: Protocol,
+        timeout: u64,
+    ) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_reachable(self.name, port, proto, timeout))
+            .and_then(Output::to_bool)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::backend::Backend;
+use crate::provider::error;
+use crate::provider::linux_audit_system::LinuxAuditSystemProvider;
+use crate::provider::Output;
+
+pub struct LinuxAuditSystem<'a> {
+    backend: &'a dyn Backend,
+    provider: &'a LinuxAuditSystemProvider,
+}
+
+impl<'a> LinuxAuditSystem<'a> {
+    pub fn new(b: &'a dyn Backend, p: &'a LinuxAuditSystemProvider) -> LinuxAuditSystem<'a> {
+        LinuxAuditSystem {
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn is_enabled(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_enabled())
+            .and_then(Output::to_bool)
+    }
+
+    pub fn is_running(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_running())
+            .and_then(Output::to_bool)
+    }
+
+    pub fn has_rule(&self, rule: &'static str) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.has_rule(rule))
+            .and_then(Output::to_bool)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::backend::Backend;
+use crate::provider::error;
+use crate::provider::file::FileProvider;
+use crate::provider::Output;
+
+const ALIASES_FILE: &str = "/etc/aliases";
+const INCLUDE_DIRECTIVE: &str = ":include:";
+
+pub struct MailAlias<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a FileProvider,
+}
+
+impl<'a> MailAlias<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a FileProvider) -> MailAlias<'a> {
+        MailAlias {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn is_aliased_to(&self, target: &str) -> Result<bool, error::Error> {
+        let targets = self.targets()?;
+        Ok(targets.iter().any(|t| t == target))
+    }
+
+    pub fn targets(&self) -> Result<Vec<String>, error::Error> {
+        let contents = self.contents(ALIASES_FILE)?;
+
+        let mut targets = Vec::new();
+        let mut seen = Vec::new();
+        for (name, value) in entries(&contents) {
+            if name.eq_ignore_ascii_case(self.name) {
+                self.expand(&value, &mut targets, &mut seen)?;
+            }
+        }
+        Ok(targets)
+    }
+
+    // Adds the addresses in value to targets, replacing :include: directives
+    // with the addresses listed in the included file.
+    fn expand(
+        &self,
+        value: &str,
+        targets: &mut Vec<String>,
+        seen: &mut Vec<String>,
+    ) -> Result<(), error::Error> {
+        for address in split_addresses(value) {
+            match address.strip_prefix(INCLUDE_DIRECTIVE) {
+                Some(path) => {
+                    let path = path.trim().to_string();
+                    if seen.contains(&path) {
+                        continue;
+                    }
+                    seen.push(path.clone());
+
+                    let contents = self.contents(&path)?;
+                    for line in contents.lines() {
+                        if line.trim_start().starts_with('#') {
+                            continue;
+                        }
+                        self.expand(line, targets, seen)?;
+                    }
+                }
+                None => targets.push(address),
+            }
+        }
+        Ok(())
+    }
+
+    fn contents(&self, path: &str) -> Result<String, error::Error> {
+        self.backend
+            .handle(self.provider.contents(path))
+            .and_then(Output::to_string)
+    }
+}
+
+// Splits the contents of an aliases file into (name, value) pairs, joining
+// continuation lines (lines starting with whitespace) onto their entry.
+fn entries(contents: &str) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for line in contents.lines() {
+        if line.trim_start().starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some(last) = entries.last_mut() {
+                last.1.push(' ');
+                last.1.push_str(line.trim());
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            entries.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    entries
+}
+
+// Splits a comma separated list of addresses. Commas within double quotes
+// (e.g. in a "|command, with args" target) do not separate addresses.
+fn split_addresses(value: &str) -> Vec<String> {
+    let mut addresses = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                addresses.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    addresses.push(current.trim().to_string());
+
+    addresses.retain(|a| !a.is_empty());
+    addresses
+}
@@ -1,4 +1,13 @@
+pub mod bond;
+pub mod bridge;
+pub mod cgroup;
+pub mod docker_container;
+pub mod docker_image;
 pub mod file;
+pub mod host;
+pub mod linux_audit_system;
+pub mod mail_alias;
 pub mod package;
 pub mod port;
+pub mod repository;
 pub mod service;
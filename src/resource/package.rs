@@ -3,9 +3,18 @@ use crate::provider::error;
 use crate::provider::package::PackageProvider;
 use crate::provider::Output;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageInfo {
+    pub name: String,
+    pub version: String,
+    pub architecture: String,
+    pub repository: Option<String>,
+}
+
 pub struct Package<'a> {
     name: &'static str,
     version: Option<&'static str>,
+    architecture: Option<&'static str>,
     backend: &'a dyn Backend,
     provider: &'a PackageProvider,
 }
@@ -20,32 +29,112 @@ impl<'a> Package<'a> {
         Package {
             name: n,
             version: v,
+            architecture: None,
             backend: b,
             provider: p,
         }
     }
 
+    // Narrows the package to one architecture, as in libc6:i386 or
+    // glibc.i686, on package managers with multiarch support. Others fail
+    // with HandleFuncNotDefined.
+    pub fn with_architecture(mut self, a: &'static str) -> Package<'a> {
+        self.architecture = Some(a);
+        self
+    }
+
     pub fn is_installed(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.is_installed(self.name, self.version))
+            .handle(
+                self.provider
+                    .is_installed(self.name, self.version, self.architecture),
+            )
             .and_then(Output::to_bool)
     }
 
     pub fn version(&self) -> Result<String, error::Error> {
         self.backend
-            .handle(self.provider.version(self.name, self.version))
+            .handle(
+                self.provider
+                    .version(self.name, self.version, self.architecture),
+            )
             .and_then(Output::to_string)
     }
 
     pub fn remove(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.remove(self.name, self.version))
+            .handle(
+                self.provider
+                    .remove(self.name, self.version, self.architecture),
+            )
             .and_then(Output::to_bool)
     }
 
     pub fn install(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.install(self.name, self.version))
+            .handle(
+                self.provider
+                    .install(self.name, self.version, self.architecture),
+            )
+            .and_then(Output::to_bool)
+    }
+
+    pub fn upgrade(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(
+                self.provider
+                    .upgrade(self.name, self.version, self.architecture),
+            )
+            .and_then(Output::to_bool)
+    }
+
+    pub fn version_satisfies(&self, requirement: &'static str) -> Result<bool, error::Error> {
+        self.backend
+            .handle(
+                self.provider
+                    .version_satisfies(self.name, requirement, self.architecture),
+            )
+            .and_then(Output::to_bool)
+    }
+
+    pub fn hold(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.hold(self.name, self.architecture))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn unhold(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.unhold(self.name, self.architecture))
             .and_then(Output::to_bool)
     }
+
+    pub fn is_held(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_held(self.name, self.architecture))
+            .and_then(Output::to_bool)
+    }
+}
+
+pub fn installed(b: &dyn Backend, p: &PackageProvider) -> Result<Vec<PackageInfo>, error::Error> {
+    b.handle(p.packages())
+        .and_then(Output::to_vec)
+        .and_then(|l| l.iter().map(|r| to_package_info(r)).collect())
+}
+
+pub fn upgradable(b: &dyn Backend, p: &PackageProvider) -> Result<Vec<PackageInfo>, error::Error> {
+    b.handle(p.upgradable())
+        .and_then(Output::to_vec)
+        .and_then(|l| l.iter().map(|r| to_package_info(r)).collect())
+}
+
+fn to_package_info(record: &str) -> Result<PackageInfo, error::Error> {
+    let v: serde_json::Value = serde_json::from_str(record)?;
+    let field = |k: &str| v[k].as_str().unwrap_or_default().to_string();
+    Ok(PackageInfo {
+        name: field("name"),
+        version: field("version"),
+        architecture: field("architecture"),
+        repository: v["repository"].as_str().map(|r| r.to_string()),
+    })
 }
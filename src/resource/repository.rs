@@ -0,0 +1,44 @@
+use crate::backend::Backend;
+use crate::provider::error;
+use crate::provider::repository::RepositoryProvider;
+use crate::provider::Output;
+
+pub struct Repository<'a> {
+    name: &'static str,
+    backend: &'a dyn Backend,
+    provider: &'a RepositoryProvider,
+}
+
+impl<'a> Repository<'a> {
+    pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a RepositoryProvider) -> Repository<'a> {
+        Repository {
+            name: n,
+            backend: b,
+            provider: p,
+        }
+    }
+
+    pub fn exists(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.exists(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn is_enabled(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_enabled(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn add(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.add(self.name))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn remove(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.remove(self.name))
+            .and_then(Output::to_bool)
+    }
+}
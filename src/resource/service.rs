@@ -4,11 +4,30 @@ use std::ffi::CString;
 
 use crate::backend::Backend;
 use crate::provider::error;
+use crate::provider::service::LogQuery;
+use crate::provider::service::Scope;
 use crate::provider::service::ServiceProvider;
 use crate::provider::Output;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    pub priority: Option<u8>,
+    // Microseconds since the epoch.
+    pub timestamp: Option<u64>,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub running: bool,
+    pub enabled: bool,
+}
+
 pub struct Service<'a> {
     name: &'static str,
+    scope: Scope,
+    log_files: Option<&'static [&'static str]>,
     backend: &'a dyn Backend,
     provider: &'a ServiceProvider,
     error: Option<error::Error>,
@@ -18,59 +37,250 @@ impl<'a> Service<'a> {
     pub fn new(n: &'static str, b: &'a dyn Backend, p: &'a ServiceProvider) -> Service<'a> {
         Service {
             name: n,
+            scope: Scope::System,
+            log_files: None,
             backend: b,
             provider: p,
             error: None,
         }
     }
 
+    // Manages the service as a unit of a user's own service manager instead
+    // of the system one.
+    pub fn with_scope(mut self, scope: Scope) -> Service<'a> {
+        self.scope = scope;
+        self
+    }
+
+    // Log files to read for init systems without a journal.
+    pub fn with_log_files(mut self, files: &'static [&'static str]) -> Service<'a> {
+        self.log_files = Some(files);
+        self
+    }
+
     pub fn is_running(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.is_running(self.name))
+            .handle(self.provider.is_running(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn is_enabled(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.is_enabled(self.name))
+            .handle(self.provider.is_enabled(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn enable(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.enable(self.name))
+            .handle(self.provider.enable(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn disable(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.disable(self.name))
+            .handle(self.provider.disable(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn start(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.start(self.name))
+            .handle(self.provider.start(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn stop(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.stop(self.name))
+            .handle(self.provider.stop(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn reload(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.reload(self.name))
+            .handle(self.provider.reload(self.name, self.scope))
             .and_then(Output::to_bool)
     }
 
     pub fn restart(&self) -> Result<bool, error::Error> {
         self.backend
-            .handle(self.provider.restart(self.name))
+            .handle(self.provider.restart(self.name, self.scope))
+            .and_then(Output::to_bool)
+    }
+
+    // The last `lines` entries, optionally only those since a time
+    // journalctl understands, such as "1 hour ago". Inits without a journal
+    // read log files, which can't be filtered by time.
+    pub fn logs(
+        &self,
+        since: Option<&'static str>,
+        lines: u32,
+    ) -> Result<Vec<LogEntry>, error::Error> {
+        let query = LogQuery {
+            since,
+            lines,
+            files: self.log_files,
+        };
+        let records = self
+            .backend
+            .handle(self.provider.logs(self.name, query, self.scope))
+            .and_then(Output::to_vec)?;
+        records.iter().map(|r| to_log_entry(r)).collect()
+    }
+
+    pub fn mask(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.mask(self.name, self.scope))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn unmask(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.unmask(self.name, self.scope))
+            .and_then(Output::to_bool)
+    }
+
+    pub fn is_masked(&self) -> Result<bool, error::Error> {
+        self.backend
+            .handle(self.provider.is_masked(self.name, self.scope))
             .and_then(Output::to_bool)
     }
+
+    pub fn property(&self, property: &'static str) -> Result<String, error::Error> {
+        self.backend
+            .handle(self.provider.property(self.name, property, self.scope))
+            .and_then(Output::to_string)
+    }
+
+    pub fn sub_state(&self) -> Result<String, error::Error> {
+        self.property("SubState")
+    }
+
+    // 0 when the service has no main process.
+    pub fn main_pid(&self) -> Result<u32, error::Error> {
+        Ok(self.property("MainPID")?.parse()?)
+    }
+
+    pub fn restart_policy(&self) -> Result<String, error::Error> {
+        self.property("Restart")
+    }
+
+    pub fn exec_start(&self) -> Result<Vec<String>, error::Error> {
+        let v = self.property("ExecStart")?;
+        Ok(v.lines().map(|l| l.to_string()).collect())
+    }
+
+    pub fn user(&self) -> Result<String, error::Error> {
+        self.property("User")
+    }
+
+    pub fn environment(&self) -> Result<Vec<String>, error::Error> {
+        Ok(split_quoted(&self.property("Environment")?))
+    }
+
+    pub fn n_restarts(&self) -> Result<u32, error::Error> {
+        Ok(self.property("NRestarts")?.parse()?)
+    }
+
+    // Microseconds since the epoch, 0 when the unit has never been active.
+    pub fn active_enter_timestamp(&self) -> Result<u64, error::Error> {
+        Ok(self.property("ActiveEnterTimestamp")?.parse()?)
+    }
+
+    // When a timer elapses next, in microseconds since the epoch.
+    pub fn next_elapse(&self) -> Result<u64, error::Error> {
+        Ok(self.property("NextElapseUSecRealtime")?.parse()?)
+    }
+
+    pub fn last_trigger(&self) -> Result<u64, error::Error> {
+        Ok(self.property("LastTriggerUSec")?.parse()?)
+    }
+
+    // Addresses a socket listens on, such as /run/docker.sock or [::]:22.
+    pub fn listen_addresses(&self) -> Result<Vec<String>, error::Error> {
+        let v = self.property("Listen")?;
+        let addresses = v
+            .lines()
+            .map(|l| match l.rsplit_once(" (") {
+                Some((address, _)) => address.to_string(),
+                None => l.to_string(),
+            })
+            .collect();
+        Ok(addresses)
+    }
+
+    // Units a target pulls in.
+    pub fn wants(&self) -> Result<Vec<String>, error::Error> {
+        Ok(split_quoted(&self.property("Wants")?))
+    }
+}
+
+pub fn services(b: &dyn Backend, p: &ServiceProvider) -> Result<Vec<ServiceInfo>, error::Error> {
+    let list = b.handle(p.services()).and_then(Output::to_vec)?;
+    let services = list
+        .iter()
+        .filter_map(|entry| {
+            let mut fields = entry.split('\t');
+            Some(ServiceInfo {
+                name: fields.next()?.to_string(),
+                running: fields.next()? == "true",
+                enabled: fields.next()? == "true",
+            })
+        })
+        .collect();
+    Ok(services)
+}
+
+pub fn daemon_reload(b: &dyn Backend, p: &ServiceProvider) -> Result<bool, error::Error> {
+    b.handle(p.daemon_reload(Scope::System))
+        .and_then(Output::to_bool)
+}
+
+// Parses a journal export JSON record. Fields are strings, except for
+// MESSAGE which is an array of bytes when it is not valid UTF-8.
+fn to_log_entry(record: &str) -> Result<LogEntry, error::Error> {
+    let v: serde_json::Value = serde_json::from_str(record)?;
+    let message = match &v["MESSAGE"] {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(a) => {
+            let bytes: Vec<u8> = a
+                .iter()
+                .filter_map(|b| b.as_u64())
+                .map(|b| b as u8)
+                .collect();
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+        _ => String::new(),
+    };
+
+    Ok(LogEntry {
+        priority: v["PRIORITY"].as_str().and_then(|p| p.parse().ok()),
+        timestamp: v["__REALTIME_TIMESTAMP"]
+            .as_str()
+            .and_then(|t| t.parse().ok()),
+        message,
+    })
+}
+
+// Splits space separated values, some of which are double quoted because
+// they contain spaces themselves.
+fn split_quoted(s: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut quoted = false;
+    for c in s.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !value.is_empty() {
+                    values.push(std::mem::take(&mut value));
+                }
+            }
+            c => value.push(c),
+        }
+    }
+    if !value.is_empty() {
+        values.push(value);
+    }
+    values
 }
 
 // Wrapper functions for FFI
@@ -0,0 +1,61 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::bond;
+use specinfra::provider::bond::BondProvider;
+use specinfra::resource::bond::Bond;
+
+const ACTIVE_BACKUP: &str = "Ethernet Channel Bonding Driver: v5.15.0
+
+Bonding Mode: fault-tolerance (active-backup)
+Primary Slave: None
+Currently Active Slave: eth0
+MII Status: up
+MII Polling Interval (ms): 100
+Up Delay (ms): 0
+Down Delay (ms): 0
+
+Slave Interface: eth0
+MII Status: up
+Speed: 1000 Mbps
+
+Slave Interface: eth1
+MII Status: up
+Speed: 1000 Mbps
+";
+
+const ROUND_ROBIN: &str = "Ethernet Channel Bonding Driver: v5.15.0
+
+Bonding Mode: load balancing (round-robin)
+MII Status: up
+MII Polling Interval (ms): 0
+";
+
+#[test]
+fn bond_resource() {
+    let b = Mock::new()
+        .on("test -f /proc/net/bonding/bond0", "")
+        .on("cat /proc/net/bonding/bond0", ACTIVE_BACKUP)
+        .on("cat /proc/net/bonding/bond1", ROUND_ROBIN);
+    let p = BondProvider::new(
+        Box::new(bond::inline::null::Null),
+        Box::new(bond::shell::linux::Linux),
+    );
+
+    let bond0 = Bond::new("bond0", &b, &p);
+    assert!(bond0.exists().unwrap());
+    assert_eq!(bond0.mode().unwrap(), "fault-tolerance (active-backup)");
+    assert_eq!(bond0.slaves().unwrap(), vec!["eth0", "eth1"]);
+    assert_eq!(bond0.active_slave().unwrap(), Some("eth0".to_string()));
+    assert_eq!(bond0.miimon().unwrap(), 100);
+
+    let bond1 = Bond::new("bond1", &b, &p);
+    assert_eq!(bond1.mode().unwrap(), "load balancing (round-robin)");
+    assert!(bond1.slaves().unwrap().is_empty());
+    assert_eq!(bond1.active_slave().unwrap(), None);
+    assert_eq!(bond1.miimon().unwrap(), 0);
+
+    assert!(!Bond::new("bond2", &b, &p).exists().unwrap());
+}
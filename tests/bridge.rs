@@ -0,0 +1,30 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::bridge;
+use specinfra::provider::bridge::BridgeProvider;
+use specinfra::resource::bridge::Bridge;
+
+#[test]
+fn bridge_resource() {
+    let b = Mock::new()
+        .on("test -d /sys/class/net/br0/bridge", "")
+        .on("ls -1 /sys/class/net/br0/brif", "veth1\neth0\n")
+        .on("cat /sys/class/net/br0/bridge/stp_state", "1\n")
+        .on("cat /sys/class/net/docker0/bridge/stp_state", "0\n");
+    let p = BridgeProvider::new(
+        Box::new(bridge::inline::null::Null),
+        Box::new(bridge::shell::linux::Linux),
+    );
+
+    let br0 = Bridge::new("br0", &b, &p);
+    assert!(br0.exists().unwrap());
+    assert_eq!(br0.interfaces().unwrap(), vec!["eth0", "veth1"]);
+    assert!(br0.stp_enabled().unwrap());
+
+    let docker0 = Bridge::new("docker0", &b, &p);
+    assert!(!docker0.exists().unwrap());
+    assert!(!docker0.stp_enabled().unwrap());
+}
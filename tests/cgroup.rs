@@ -0,0 +1,32 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::cgroup;
+use specinfra::provider::cgroup::CgroupProvider;
+use specinfra::resource::cgroup::Cgroup;
+
+#[test]
+fn cgroup_resource() {
+    let b = Mock::new()
+        .on("memory.max", "536870912")
+        .on("pids.max", "max");
+    let p = CgroupProvider::new(
+        Box::new(cgroup::inline::null::Null),
+        Box::new(cgroup::shell::linux::Linux),
+    );
+    let nginx = Cgroup::new("/system.slice/nginx.service", &b, &p);
+
+    assert_eq!(nginx.value("memory.max").unwrap(), "536870912");
+    assert_eq!(nginx.value("pids.max").unwrap(), "max");
+
+    assert_eq!(
+        b.commands()[0],
+        "if test -f /sys/fs/cgroup/cgroup.controllers; \
+         then cat /sys/fs/cgroup/system.slice/nginx.service/memory.max; \
+         elif test -e /sys/fs/cgroup/memory/system.slice/nginx.service/memory.max; \
+         then cat /sys/fs/cgroup/memory/system.slice/nginx.service/memory.max; \
+         else cat /sys/fs/cgroup/unified/system.slice/nginx.service/memory.max; fi"
+    );
+}
@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+
+use specinfra::backend;
+use specinfra::backend::command::{Command, CommandResult};
+use specinfra::backend::error::CommandError;
+use specinfra::backend::Backend;
+use specinfra::platform::platform::Platform;
+use specinfra::provider;
+use specinfra::provider::{HandleFunc, Output};
+
+// A backend which answers commands with canned output, so that shell
+// providers can be tested without the tools they drive.
+pub struct Mock {
+    responses: Vec<(&'static str, &'static str)>,
+    failures: Vec<&'static str>,
+    commands: RefCell<Vec<String>>,
+    inputs: RefCell<Vec<Vec<u8>>>,
+}
+
+impl Mock {
+    pub fn new() -> Mock {
+        Mock {
+            responses: Vec::new(),
+            failures: Vec::new(),
+            commands: RefCell::new(Vec::new()),
+            inputs: RefCell::new(Vec::new()),
+        }
+    }
+
+    // Commands containing `pattern` succeed and print `stdout`. Any other
+    // command fails.
+    pub fn on(mut self, pattern: &'static str, stdout: &'static str) -> Mock {
+        self.responses.push((pattern, stdout));
+        self
+    }
+
+    // Commands containing `pattern` exit non-zero and are reported the way
+    // the SSH backend reports them, as a result with `success` unset.
+    pub fn fail(mut self, pattern: &'static str) -> Mock {
+        self.failures.push(pattern);
+        self
+    }
+
+    pub fn commands(&self) -> Vec<String> {
+        self.commands.borrow().clone()
+    }
+
+    // The data given on standard input, for commands which had any.
+    pub fn inputs(&self) -> Vec<Vec<u8>> {
+        self.inputs.borrow().clone()
+    }
+}
+
+impl Backend for Mock {
+    fn detect_platform(&self) -> Option<Box<dyn Platform>> {
+        None
+    }
+
+    fn handle(&self, handle_func: Box<HandleFunc>) -> Result<Output, provider::error::Error> {
+        (handle_func.shell)(self)
+    }
+
+    fn run_command(&self, c: Command) -> Result<CommandResult, backend::error::Error> {
+        self.commands.borrow_mut().push(c.string.clone());
+        if let Some(ref data) = c.stdin {
+            self.inputs.borrow_mut().push(data.clone());
+        }
+
+        if self.failures.iter().any(|p| c.string.contains(p)) {
+            return Ok(CommandResult {
+                stdout: "".to_string(),
+                stderr: "".to_string(),
+                success: false,
+                code: 1,
+            });
+        }
+
+        match self.responses.iter().find(|r| c.string.contains(r.0)) {
+            Some(&(_, stdout)) => Ok(CommandResult {
+                stdout: stdout.trim().to_string(),
+                stderr: "".to_string(),
+                success: true,
+                code: 0,
+            }),
+            None => {
+                let e = CommandError {
+                    code: 1,
+                    message: format!("unexpected command: {}", c.string),
+                };
+                Err(e.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+extern crate specinfra;
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::Mock;
+use specinfra::provider::docker;
+use specinfra::provider::docker::DockerProvider;
+use specinfra::resource::docker_container::{DockerContainer, Mount};
+use specinfra::resource::docker_image::DockerImage;
+
+const CONTAINER: &str = r#"{
+  "Id": "4fa6e0f0c6786287e131c3852c58a2e01cc697a68231826813597e4994f1d6e2",
+  "Name": "/web",
+  "State": {"Status": "running", "Running": true, "Pid": 1234},
+  "HostConfig": {"RestartPolicy": {"Name": "always", "MaximumRetryCount": 0}},
+  "Mounts": [
+    {
+      "Type": "bind",
+      "Source": "/srv/www",
+      "Destination": "/usr/share/nginx/html",
+      "Mode": "ro",
+      "RW": false
+    }
+  ],
+  "Config": {
+    "Image": "nginx:1.25",
+    "Env": ["PATH=/usr/local/sbin:/usr/local/bin", "NGINX_VERSION=1.25.3"],
+    "Labels": {"maintainer": "NGINX Docker Maintainers"}
+  }
+}"#;
+
+const IMAGE: &str = r#"{
+  "Id": "sha256:a8758716bb6aa4d90071160d27028fe4eaee7ce8166221a97d30440c8eac2be6",
+  "Created": "2023-10-25T21:31:18.211425114Z",
+  "Config": {"Labels": null}
+}"#;
+
+fn provider() -> DockerProvider {
+    DockerProvider::new(
+        Box::new(docker::inline::null::Null),
+        Box::new(docker::shell::docker::Docker),
+    )
+}
+
+#[test]
+fn docker_container_resource() {
+    let b = Mock::new()
+        .on(
+            "docker inspect --type container --format '{{json .}}' web",
+            CONTAINER,
+        )
+        .on(
+            "docker inspect --type container --format '{{.Id}}' web",
+            "4fa6e0f0c678",
+        );
+    let p = provider();
+    let container = DockerContainer::new("web", &b, &p);
+
+    assert!(container.exists().unwrap());
+    assert!(container.is_running().unwrap());
+    assert_eq!(container.image().unwrap(), "nginx:1.25");
+    assert_eq!(container.restart_policy().unwrap(), "always");
+    assert!(container
+        .env()
+        .unwrap()
+        .contains(&"NGINX_VERSION=1.25.3".to_string()));
+    assert_eq!(
+        container.mounts().unwrap(),
+        vec![Mount {
+            kind: "bind".to_string(),
+            source: "/srv/www".to_string(),
+            destination: "/usr/share/nginx/html".to_string(),
+            mode: "ro".to_string(),
+            rw: false,
+        }]
+    );
+
+    let mut labels = HashMap::new();
+    labels.insert(
+        "maintainer".to_string(),
+        "NGINX Docker Maintainers".to_string(),
+    );
+    assert_eq!(container.labels().unwrap(), labels);
+}
+
+#[test]
+fn docker_container_does_not_exist() {
+    let b = Mock::new();
+    let p = provider();
+    let container = DockerContainer::new("web", &b, &p);
+
+    assert_eq!(container.exists().unwrap(), false);
+    assert!(container.is_running().is_err());
+}
+
+#[test]
+fn docker_image_resource() {
+    let b = Mock::new()
+        .on(
+            "docker inspect --type image --format '{{json .}}' nginx:1.25",
+            IMAGE,
+        )
+        .on(
+            "docker inspect --type image --format '{{.Id}}' nginx:1.25",
+            "sha256:a875",
+        );
+    let p = provider();
+    let image = DockerImage::new("nginx:1.25", &b, &p);
+
+    assert!(image.exists().unwrap());
+    assert_eq!(
+        image.id().unwrap(),
+        "sha256:a8758716bb6aa4d90071160d27028fe4eaee7ce8166221a97d30440c8eac2be6"
+    );
+    assert_eq!(image.created().unwrap(), "2023-10-25T21:31:18.211425114Z");
+    assert!(image.labels().unwrap().is_empty());
+}
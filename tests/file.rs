@@ -1,7 +1,16 @@
 extern crate specinfra;
 
+use std::fs;
+use std::os::unix::fs::{symlink, PermissionsExt};
+use std::path;
+
 use specinfra::backend;
+use specinfra::backend::command::Command;
+use specinfra::backend::Backend;
+use specinfra::provider::file;
 use specinfra::provider::file::inline::null::Null;
+use specinfra::provider::file::FileProvider;
+use specinfra::resource::file::File;
 use specinfra::Specinfra;
 
 #[test]
@@ -20,6 +29,16 @@ fn file_resource_with_shell_provider() {
     test_file_resource(s);
 }
 
+#[test]
+fn direct_backend_stdin_larger_than_pipe() {
+    let b = backend::direct::Direct::new();
+    let data = vec![b'x'; 1 << 20];
+    let mut c = Command::new("cat");
+    c.stdin(&data);
+    let res = b.run_command(c).unwrap();
+    assert_eq!(res.stdout.len(), data.len());
+}
+
 #[test]
 fn file_not_exist_with_inline_provider() {
     let b = backend::direct::Direct::new();
@@ -35,6 +54,34 @@ fn file_not_exist_with_shell_provider() {
     test_file_not_exit(s);
 }
 
+#[test]
+fn file_write_with_inline_provider() {
+    let b = backend::direct::Direct::new();
+    let p = FileProvider::new(
+        Box::new(file::inline::posix::Posix),
+        Box::new(file::shell::null::Null),
+    );
+    test_file_write(
+        "/tmp/specinfra_file_write_inline",
+        "/tmp/specinfra_file_write_inline.link",
+        &b,
+        &p,
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn file_write_with_shell_provider() {
+    let b = backend::direct::Direct::new();
+    let p = FileProvider::new(Box::new(Null), Box::new(file::shell::linux::Linux));
+    test_file_write(
+        "/tmp/specinfra_file_write_shell",
+        "/tmp/specinfra_file_write_shell.link",
+        &b,
+        &p,
+    );
+}
+
 #[test]
 #[cfg(target_os = "macos")]
 fn file_link_on_macos_with_inline_provider() {
@@ -105,6 +152,48 @@ fn test_file_resource(s: Specinfra) {
     assert!(file.size().unwrap() > 0);
 }
 
+fn test_file_write(name: &'static str, link: &'static str, b: &dyn Backend, p: &FileProvider) {
+    let _ = fs::remove_file(name);
+    let file = File::new(name, b, p);
+
+    assert!(file.replace(b"first\n").unwrap());
+    assert_eq!(fs::read(name).unwrap(), b"first\n");
+
+    fs::set_permissions(name, fs::Permissions::from_mode(0o600)).unwrap();
+    assert!(file.write_contents(b"second\n").unwrap());
+    assert!(file.append(b"third\n").unwrap());
+    assert_eq!(fs::read(name).unwrap(), b"second\nthird\n");
+
+    assert!(file.replace(b"fourth\n").unwrap());
+    assert_eq!(fs::read(name).unwrap(), b"fourth\n");
+    assert_eq!(file.mode().unwrap(), 0o600);
+
+    fs::set_permissions(name, fs::Permissions::from_mode(0o4755)).unwrap();
+    assert!(file.replace(b"fourth\n").unwrap());
+    assert_eq!(file.mode().unwrap(), 0o4755);
+
+    let _ = fs::remove_file(link);
+    symlink(name, link).unwrap();
+    assert!(File::new(link, b, p).replace(b"fifth\n").unwrap());
+    assert!(fs::symlink_metadata(link).unwrap().file_type().is_symlink());
+    assert_eq!(fs::read(name).unwrap(), b"fifth\n");
+    fs::remove_file(link).unwrap();
+
+    let dir = path::Path::new(name).parent().unwrap();
+    let prefix = format!(
+        ".{}",
+        path::Path::new(name).file_name().unwrap().to_str().unwrap()
+    );
+    assert!(!fs::read_dir(dir).unwrap().any(|e| e
+        .unwrap()
+        .file_name()
+        .to_str()
+        .unwrap()
+        .starts_with(&prefix)));
+
+    fs::remove_file(name).unwrap();
+}
+
 fn test_file_not_exit(s: Specinfra) {
     let file = s.file("file_does_not_exist");
     assert_eq!(file.exist().unwrap(), false);
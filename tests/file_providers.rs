@@ -0,0 +1,62 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::file;
+use specinfra::provider::file::FileProvider;
+use specinfra::resource::file::File;
+
+#[test]
+fn linux_file_write() {
+    let b = Mock::new().on("cat >", "");
+    let p = FileProvider::new(
+        Box::new(file::inline::null::Null),
+        Box::new(file::shell::linux::Linux),
+    );
+
+    let motd = File::new("/etc/motd", &b, &p);
+    assert!(motd.write_contents(b"Welcome '$USER'\n").unwrap());
+    assert!(motd.append(b"Bye\n").unwrap());
+
+    assert_eq!(b.commands(), vec!["cat > /etc/motd", "cat >> /etc/motd"]);
+    assert_eq!(
+        b.inputs(),
+        vec![b"Welcome '$USER'\n".to_vec(), b"Bye\n".to_vec()]
+    );
+}
+
+#[test]
+fn linux_file_write_failure() {
+    let b = Mock::new().fail("/etc/readonly");
+    let p = FileProvider::new(
+        Box::new(file::inline::null::Null),
+        Box::new(file::shell::linux::Linux),
+    );
+
+    let f = File::new("/etc/readonly/motd", &b, &p);
+    assert!(!f.write_contents(b"Welcome\n").unwrap());
+    assert!(!f.append(b"Bye\n").unwrap());
+    assert!(!f.replace(b"Welcome\n").unwrap());
+}
+
+#[test]
+fn bsd_file_replace() {
+    let b = Mock::new().on("mktemp", "");
+    let p = FileProvider::new(
+        Box::new(file::inline::null::Null),
+        Box::new(file::shell::bsd::Bsd),
+    );
+
+    let hosts = File::new("/etc/hosts", &b, &p);
+    assert!(hosts.replace(b"127.0.0.1 localhost\n").unwrap());
+
+    let commands = b.commands();
+    assert_eq!(commands.len(), 1);
+    assert!(commands[0].contains("f=$(readlink -f /etc/hosts)"));
+    assert!(commands[0]
+        .contains("chown $(stat -f%u:%g \"$f\") \"$t\" && chmod $(stat -f%Mp%Lp \"$f\") \"$t\""));
+    assert!(commands[0].contains("mv -f \"$t\" \"$f\""));
+    assert!(!commands[0].contains("localhost"));
+    assert_eq!(b.inputs(), vec![b"127.0.0.1 localhost\n".to_vec()]);
+}
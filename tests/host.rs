@@ -0,0 +1,59 @@
+extern crate specinfra;
+
+use std::net::TcpListener;
+
+use specinfra::backend::direct::Direct;
+use specinfra::provider::host;
+use specinfra::provider::host::{HostProvider, Protocol, Resolver};
+use specinfra::resource::host::Host;
+
+#[test]
+#[cfg(target_os = "linux")]
+fn host_resource_with_inline_provider() {
+    let b = Direct::new();
+    let p = HostProvider::new(
+        Box::new(host::inline::posix::Posix),
+        Box::new(host::shell::linux::Linux),
+    );
+    test_host_resource(Host::new("localhost", &b, &p));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn host_resource_with_shell_provider() {
+    let b = Direct::new();
+    let p = HostProvider::new(
+        Box::new(host::inline::null::Null),
+        Box::new(host::shell::linux::Linux),
+    );
+    test_host_resource(Host::new("localhost", &b, &p));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn unresolvable_host_with_shell_provider() {
+    let b = Direct::new();
+    let p = HostProvider::new(
+        Box::new(host::inline::null::Null),
+        Box::new(host::shell::linux::Linux),
+    );
+    assert!(Host::new("specinfra.invalid", &b, &p).ipaddress().is_err());
+}
+
+fn test_host_resource(host: Host) {
+    assert!(host.is_resolvable(Resolver::Any).unwrap());
+    assert!(host.is_resolvable(Resolver::Hosts).unwrap());
+
+    let ipaddress = host.ipaddress().unwrap();
+    assert!(ipaddress == "127.0.0.1" || ipaddress == "::1");
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port() as usize;
+    assert!(host.is_reachable(Some(port), Protocol::Tcp, 1).unwrap());
+
+    drop(listener);
+    assert_eq!(
+        host.is_reachable(Some(port), Protocol::Tcp, 1).unwrap(),
+        false
+    );
+}
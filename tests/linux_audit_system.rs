@@ -0,0 +1,64 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::linux_audit_system;
+use specinfra::provider::linux_audit_system::LinuxAuditSystemProvider;
+use specinfra::resource::linux_audit_system::LinuxAuditSystem;
+
+const STATUS: &str = "
+enabled 2
+failure 1
+pid 712
+rate_limit 0
+backlog_limit 8192
+lost 0
+backlog 0
+";
+
+const LEGACY_STATUS: &str =
+    "AUDIT_STATUS: enabled=0 flag=1 pid=0 rate_limit=0 backlog_limit=320 lost=0 backlog=0";
+
+const RULES: &str = "
+-w /etc/group -p wa -k identity
+-w /etc/passwd -p wa -k identity
+-a always,exit -F arch=b64 -S adjtimex,settimeofday -F key=time-change
+";
+
+fn provider() -> LinuxAuditSystemProvider {
+    LinuxAuditSystemProvider::new(
+        Box::new(linux_audit_system::inline::null::Null),
+        Box::new(linux_audit_system::shell::auditctl::Auditctl),
+    )
+}
+
+#[test]
+fn linux_audit_system_resource() {
+    let b = Mock::new()
+        .on("auditctl -s", STATUS)
+        .on("auditctl -l", RULES);
+    let p = provider();
+    let audit = LinuxAuditSystem::new(&b, &p);
+
+    assert!(audit.is_enabled().unwrap());
+    assert!(audit.is_running().unwrap());
+    assert!(audit.has_rule("-w /etc/passwd -p wa -k identity").unwrap());
+    assert!(audit
+        .has_rule("-a always,exit  -F arch=b64 -S adjtimex,settimeofday -F key=time-change")
+        .unwrap());
+    assert_eq!(
+        audit.has_rule("-w /etc/shadow -p wa -k identity").unwrap(),
+        false
+    );
+}
+
+#[test]
+fn linux_audit_system_with_legacy_status() {
+    let b = Mock::new().on("auditctl -s", LEGACY_STATUS);
+    let p = provider();
+    let audit = LinuxAuditSystem::new(&b, &p);
+
+    assert_eq!(audit.is_enabled().unwrap(), false);
+    assert_eq!(audit.is_running().unwrap(), false);
+}
@@ -0,0 +1,71 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::file;
+use specinfra::provider::file::FileProvider;
+use specinfra::resource::mail_alias::MailAlias;
+
+const ALIASES: &str = r#"
+# Basic system aliases -- these MUST be present.
+mailer-daemon:	postmaster
+postmaster:	root
+
+# General redirections for pseudo accounts.
+bin:		root
+daemon:		root
+
+ops:		alice,
+		bob@example.com,
+		"|/usr/local/bin/page --team ops, oncall"
+staff:		:include:/etc/mail/staff
+"#;
+
+const STAFF: &str = "
+# Maintained by the ops team
+carol, dave
+";
+
+fn provider() -> FileProvider {
+    FileProvider::new(
+        Box::new(file::inline::null::Null),
+        Box::new(file::shell::linux::Linux),
+    )
+}
+
+#[test]
+fn mail_alias_resource() {
+    let b = Mock::new()
+        .on("cat /etc/aliases", ALIASES)
+        .on("cat /etc/mail/staff", STAFF);
+    let p = provider();
+
+    let postmaster = MailAlias::new("postmaster", &b, &p);
+    assert!(postmaster.is_aliased_to("root").unwrap());
+    assert_eq!(postmaster.is_aliased_to("daemon").unwrap(), false);
+
+    let ops = MailAlias::new("ops", &b, &p);
+    assert_eq!(
+        ops.targets().unwrap(),
+        vec![
+            "alice",
+            "bob@example.com",
+            "|/usr/local/bin/page --team ops, oncall",
+        ]
+    );
+
+    let staff = MailAlias::new("staff", &b, &p);
+    assert_eq!(staff.targets().unwrap(), vec!["carol", "dave"]);
+    assert!(staff.is_aliased_to("dave").unwrap());
+}
+
+#[test]
+fn mail_alias_not_defined() {
+    let b = Mock::new().on("cat /etc/aliases", ALIASES);
+    let p = provider();
+
+    let nobody = MailAlias::new("nobody", &b, &p);
+    assert!(nobody.targets().unwrap().is_empty());
+    assert_eq!(nobody.is_aliased_to("root").unwrap(), false);
+}
@@ -0,0 +1,885 @@
+extern crate specinfra;
+
+mod common;
+
+use std::path::Path;
+
+use common::Mock;
+use specinfra::backend::direct::Direct;
+use specinfra::provider::error::Error;
+use specinfra::provider::package;
+use specinfra::provider::package::inline::dpkg;
+use specinfra::provider::package::inline::rpmdb;
+use specinfra::provider::package::PackageProvider;
+use specinfra::resource::package::installed;
+use specinfra::resource::package::upgradable;
+use specinfra::resource::package::Package;
+use specinfra::resource::package::PackageInfo;
+
+#[test]
+fn dnf_package() {
+    let b = Mock::new()
+        .on(
+            "rpm -q --qf '%{EPOCHNUM}:%{VERSION}-%{RELEASE}\\n' nginx",
+            "1:1.14.1-9.module_el8.0.0+184+e34b5b83",
+        )
+        .on(
+            "rpm -q --qf '%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\\n' nginx",
+            "1:1.14.1-9.module_el8.0.0+184+e34b5b83",
+        )
+        .on("dnf -y", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::dnf::Dnf),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.is_installed().unwrap());
+    assert_eq!(
+        nginx.version().unwrap(),
+        "1:1.14.1-9.module_el8.0.0+184+e34b5b83"
+    );
+
+    for v in [
+        "1:1.14.1-9.module_el8.0.0+184+e34b5b83",
+        "1.14.1-9.module_el8.0.0+184+e34b5b83",
+        "1.14.1",
+        "1:1.14.1",
+    ] {
+        assert!(Package::new("nginx", Some(v), &b, &p)
+            .is_installed()
+            .unwrap());
+    }
+    for v in ["1.14.2", "2:1.14.1", "1.14"] {
+        assert!(!Package::new("nginx", Some(v), &b, &p)
+            .is_installed()
+            .unwrap());
+    }
+    assert!(!Package::new("httpd", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("nginx", Some("1:1.16.1-1.el8"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(nginx.upgrade().unwrap());
+    assert!(nginx.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "dnf -y install nginx-1:1.16.1-1.el8",
+            "dnf -y upgrade nginx",
+            "dnf -y remove nginx",
+        ]
+    );
+}
+
+#[test]
+fn apk_package() {
+    let b = Mock::new()
+        .on("apk info -e nginx", "nginx")
+        .on(
+            "apk list --installed nginx",
+            "nginx-1.24.0-r7 x86_64 {nginx} (BSD-2-Clause) [installed]",
+        )
+        .on("apk add", "")
+        .on("apk del", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::apk::Apk),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.is_installed().unwrap());
+    assert_eq!(nginx.version().unwrap(), "1.24.0-r7");
+
+    assert!(Package::new("nginx", Some("1.24.0-r7"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(Package::new("nginx", Some("1.24.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("nginx", Some("1.24.1"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("haproxy", None, &b, &p)
+        .is_installed()
+        .unwrap());
+
+    assert!(Package::new("nginx", Some("1.24.0-r7"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(nginx.upgrade().unwrap());
+    assert!(nginx.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "apk add nginx=1.24.0-r7",
+            "apk add --upgrade nginx",
+            "apk del nginx",
+        ]
+    );
+}
+
+#[test]
+fn pacman_package() {
+    let b = Mock::new()
+        .on("pacman -Q nginx", "nginx 1.24.0-1")
+        .on("pacman -S", "")
+        .on("pacman -R", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::pacman::Pacman),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.is_installed().unwrap());
+    assert_eq!(nginx.version().unwrap(), "1.24.0-1");
+    assert!(Package::new("nginx", Some("1.24.0-1"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(Package::new("nginx", Some("1.24.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("nginx", Some("1.25.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("apache", None, &b, &p).is_installed().unwrap());
+
+    assert!(nginx.install().unwrap());
+    assert!(nginx.upgrade().unwrap());
+    assert!(nginx.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "pacman -S --noconfirm --needed 'nginx'",
+            "pacman -S --noconfirm 'nginx'",
+            "pacman -R --noconfirm nginx",
+        ]
+    );
+}
+
+#[test]
+fn yum_package_with_epoch() {
+    let b = Mock::new().on(
+        "rpm -q --qf '%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\\n' perl",
+        "4:5.16.3-299.el7_9",
+    );
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::yum::Yum),
+    };
+
+    let perl = Package::new("perl", None, &b, &p);
+    assert_eq!(perl.version().unwrap(), "4:5.16.3-299.el7_9");
+    assert!(perl.version_satisfies(">= 6.0").unwrap());
+    assert!(!perl.version_satisfies("< 4:5.0").unwrap());
+}
+
+#[test]
+fn zypper_package() {
+    let b = Mock::new()
+        .on(
+            "rpm -q --qf '%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\\n' nginx",
+            "1.21.5-150400.3.3.1",
+        )
+        .on(
+            "rpm -q --qf '%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\\n' openssl",
+            "1:1.1.1l-150400.7.28.1",
+        )
+        .on("zypper -n", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::zypper::Zypper),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.is_installed().unwrap());
+    assert_eq!(nginx.version().unwrap(), "1.21.5-150400.3.3.1");
+    assert!(Package::new("nginx", Some("1.21.5"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("nginx", Some("1.21.6"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("apache2", None, &b, &p)
+        .is_installed()
+        .unwrap());
+
+    let openssl = Package::new("openssl", None, &b, &p);
+    assert_eq!(openssl.version().unwrap(), "1:1.1.1l-150400.7.28.1");
+    assert!(openssl.version_satisfies(">= 3.0").unwrap());
+    assert!(!openssl.version_satisfies("< 1:1.1.0").unwrap());
+    assert!(Package::new("openssl", Some("1:1.1.1l"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("openssl", Some("0:1.1.1l"), &b, &p)
+        .is_installed()
+        .unwrap());
+
+    assert!(Package::new("nginx", Some("1.21.5"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(nginx.upgrade().unwrap());
+    assert!(nginx.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "zypper -n install 'nginx=1.21.5'",
+            "zypper -n update 'nginx'",
+            "zypper -n remove nginx",
+        ]
+    );
+}
+
+#[test]
+fn portage_package() {
+    let b = Mock::new()
+        .on(
+            "ls -1 -d /var/db/pkg/*/nginx-[0-9]*",
+            "/var/db/pkg/www-servers/nginx-1.24.0-r2",
+        )
+        .on(
+            "ls -1 -d /var/db/pkg/www-servers/nginx-[0-9]*",
+            "/var/db/pkg/www-servers/nginx-1.24.0-r2",
+        )
+        .on("emerge", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::portage::Portage),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.is_installed().unwrap());
+    assert_eq!(nginx.version().unwrap(), "1.24.0-r2");
+    assert!(Package::new("www-servers/nginx", Some("1.24.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(Package::new("nginx", Some("1.24.0-r2"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("nginx", Some("1.24.1"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("apache", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("www-servers/nginx", Some("1.24.0-r2"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(nginx.upgrade().unwrap());
+    assert!(nginx.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "emerge --quiet '=www-servers/nginx-1.24.0-r2'",
+            "emerge --quiet --update 'nginx'",
+            "emerge --quiet --depclean nginx",
+        ]
+    );
+}
+
+#[test]
+fn homebrew_package() {
+    let b = Mock::new()
+        .on("brew list --formula --versions wget", "wget 1.21.3 1.21.4")
+        .on("brew list --cask --versions firefox", "firefox 119.0")
+        .on(
+            "brew list --formula --versions python@3.11",
+            "python@3.11 3.11.6_1",
+        )
+        .on("brew install", "")
+        .on("brew upgrade", "")
+        .on("brew uninstall", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::homebrew::Homebrew),
+    };
+
+    let wget = Package::new("wget", None, &b, &p);
+    assert!(wget.is_installed().unwrap());
+    assert_eq!(wget.version().unwrap(), "1.21.4");
+
+    assert!(Package::new("wget", Some("1.21.3"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("wget", Some("1.20.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+
+    // Versioned formulae are named as the package.
+    let python = Package::new("python@3.11", None, &b, &p);
+    assert!(python.is_installed().unwrap());
+    assert_eq!(python.version().unwrap(), "3.11.6_1");
+
+    let firefox = Package::new("firefox", None, &b, &p);
+    assert!(firefox.is_installed().unwrap());
+    assert_eq!(firefox.version().unwrap(), "119.0");
+
+    assert!(!Package::new("curl", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("postgresql@14", None, &b, &p)
+        .install()
+        .unwrap());
+    assert!(Package::new("postgresql", Some("14.9"), &b, &p)
+        .install()
+        .is_err());
+    assert!(wget.upgrade().unwrap());
+    assert!(firefox.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "brew install postgresql@14",
+            "brew upgrade wget",
+            "brew uninstall firefox",
+        ]
+    );
+}
+
+#[test]
+fn pip_package() {
+    let b = Mock::new()
+        .on(
+            "pip show requests",
+            "Name: requests\nVersion: 2.31.0\nSummary: Python HTTP for Humans.",
+        )
+        .on("pip install", "")
+        .on("pip uninstall", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::pip::Pip),
+    };
+
+    let requests = Package::new("requests", None, &b, &p);
+    assert!(requests.is_installed().unwrap());
+    assert_eq!(requests.version().unwrap(), "2.31.0");
+    assert!(Package::new("requests", Some("2.31.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("requests", Some("2.30.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("flask", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("requests", Some("2.31.0"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(requests.upgrade().unwrap());
+    assert!(requests.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "pip install 'requests==2.31.0'",
+            "pip install --upgrade 'requests'",
+            "pip uninstall -y requests",
+        ]
+    );
+}
+
+#[test]
+fn gem_package() {
+    let b = Mock::new()
+        .on(
+            "gem list -e json",
+            "\n*** LOCAL GEMS ***\n\njson (2.7.0, default: 2.6.3)",
+        )
+        .on("gem list -e nokogiri", "nokogiri (1.15.4 x86_64-linux)")
+        .on("gem list -e rails", "")
+        .on("gem install", "")
+        .on("gem update", "")
+        .on("gem uninstall", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::gem::Gem),
+    };
+
+    let json = Package::new("json", None, &b, &p);
+    assert!(json.is_installed().unwrap());
+    assert_eq!(json.version().unwrap(), "2.7.0");
+    assert!(Package::new("json", Some("2.6.3"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(Package::new("nokogiri", Some("1.15.4"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("rails", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("rails", Some("7.1.2"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(json.upgrade().unwrap());
+    assert!(json.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "gem install rails -v '7.1.2'",
+            "gem update json",
+            "gem uninstall -x -a json",
+        ]
+    );
+}
+
+#[test]
+fn npm_package() {
+    let b = Mock::new()
+        .on(
+            "npm ls -g --depth=0 --json typescript",
+            r#"{"name": "lib", "dependencies": {"typescript": {"version": "5.2.2"}}}"#,
+        )
+        .on("npm install", "")
+        .on("npm update", "")
+        .on("npm uninstall", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::npm::Npm),
+    };
+
+    let typescript = Package::new("typescript", None, &b, &p);
+    assert!(typescript.is_installed().unwrap());
+    assert_eq!(typescript.version().unwrap(), "5.2.2");
+    assert!(Package::new("typescript", Some("5.2.2"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("typescript", Some("5.1.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("yarn", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("yarn", Some("1.22.19"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(typescript.upgrade().unwrap());
+    assert!(typescript.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "npm install -g yarn@1.22.19",
+            "npm update -g typescript",
+            "npm uninstall -g typescript",
+        ]
+    );
+}
+
+#[test]
+fn cargo_package() {
+    let b = Mock::new()
+        .on(
+            "cargo install --list",
+            "cargo-edit v0.12.2:\n    cargo-add\n    cargo-rm\n\
+             ripgrep v13.0.0 (https://github.com/BurntSushi/ripgrep#af6b6c54):\n    rg",
+        )
+        .on("cargo install", "")
+        .on("cargo uninstall", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::cargo::Cargo),
+    };
+
+    let ripgrep = Package::new("ripgrep", None, &b, &p);
+    assert!(ripgrep.is_installed().unwrap());
+    assert_eq!(ripgrep.version().unwrap(), "13.0.0");
+    assert!(Package::new("cargo-edit", Some("0.12.2"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("cargo-edit", Some("0.12.1"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("cargo", None, &b, &p).is_installed().unwrap());
+
+    assert!(Package::new("bat", Some("0.24.0"), &b, &p)
+        .install()
+        .unwrap());
+    assert!(ripgrep.upgrade().unwrap());
+    assert!(ripgrep.remove().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 3..];
+    assert_eq!(
+        commands,
+        [
+            "cargo install bat --version '0.24.0'",
+            "cargo install --force ripgrep",
+            "cargo uninstall ripgrep",
+        ]
+    );
+}
+
+const DPKG_STATUS: &str = "Package: libc6
+Status: install ok installed
+Priority: optional
+Architecture: amd64
+Multi-Arch: same
+Version: 2.36-9+deb12u3
+Description: GNU C Library: Shared libraries
+ Contains the standard libraries that are used by nearly all programs on
+ the system.
+
+Package: libc6
+Status: deinstall ok config-files
+Architecture: i386
+Version: 2.36-9+deb12u1
+
+Package: nginx
+Status: hold ok installed
+Architecture: amd64
+Version: 1.22.1-9
+";
+
+#[test]
+fn dpkg_status() {
+    let packages = dpkg::parse_status(DPKG_STATUS);
+    assert_eq!(packages.len(), 2);
+
+    let libc6 = &packages["libc6"];
+    assert_eq!(libc6.len(), 2);
+    assert!(libc6[0].is_installed());
+    assert_eq!(libc6[0].architecture, "amd64");
+    assert_eq!(libc6[0].version, "2.36-9+deb12u3");
+    assert!(!libc6[1].is_installed());
+
+    assert!(packages["nginx"][0].is_installed());
+}
+
+#[test]
+fn dpkg_package() {
+    if !Path::new("/var/lib/dpkg/status").exists() {
+        return;
+    }
+
+    let b = Direct::new();
+    let p = PackageProvider {
+        inline: Box::new(dpkg::Dpkg),
+        shell: Box::new(package::shell::null::Null),
+    };
+
+    let dpkg = Package::new("dpkg", None, &b, &p);
+    assert!(dpkg.is_installed().unwrap());
+    let version = dpkg.version().unwrap();
+    assert!(!version.is_empty());
+
+    let v: &'static str = Box::leak(version.into_boxed_str());
+    assert!(Package::new("dpkg", Some(v), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("dpkg", Some("0.0.0"), &b, &p)
+        .is_installed()
+        .unwrap());
+    assert!(!Package::new("no-such-package", None, &b, &p)
+        .is_installed()
+        .unwrap());
+}
+
+fn rpm_header(strings: &[(u32, &str)], epoch: Option<u32>) -> Vec<u8> {
+    let mut index = Vec::new();
+    let mut store = Vec::new();
+
+    for (tag, value) in strings {
+        for n in [*tag, 6, store.len() as u32, 1] {
+            index.extend_from_slice(&n.to_be_bytes());
+        }
+        store.extend_from_slice(value.as_bytes());
+        store.push(0);
+    }
+    if let Some(e) = epoch {
+        while store.len() % 4 != 0 {
+            store.push(0);
+        }
+        for n in [1003, 4, store.len() as u32, 1] {
+            index.extend_from_slice(&n.to_be_bytes());
+        }
+        store.extend_from_slice(&e.to_be_bytes());
+    }
+
+    let mut blob = Vec::new();
+    blob.extend_from_slice(&((index.len() / 16) as u32).to_be_bytes());
+    blob.extend_from_slice(&(store.len() as u32).to_be_bytes());
+    blob.extend(index);
+    blob.extend(store);
+    blob
+}
+
+#[test]
+fn rpm_header_parse() {
+    let blob = rpm_header(
+        &[
+            (1000, "nginx"),
+            (1001, "1.20.1"),
+            (1002, "14.el9"),
+            (1022, "x86_64"),
+        ],
+        Some(2),
+    );
+    let nginx = rpmdb::parse_header(&blob).unwrap();
+    assert_eq!(nginx.name, "nginx");
+    assert_eq!(nginx.epoch, Some(2));
+    assert_eq!(nginx.arch, "x86_64");
+    assert_eq!(nginx.evr(), "2:1.20.1-14.el9");
+
+    let blob = rpm_header(&[(1000, "bash"), (1001, "5.1.8"), (1002, "6.el9")], None);
+    assert_eq!(rpmdb::parse_header(&blob).unwrap().evr(), "5.1.8-6.el9");
+
+    assert_eq!(rpmdb::parse_header(&blob[..20]), None);
+}
+
+fn info(name: &str, version: &str, arch: &str, repository: Option<&str>) -> PackageInfo {
+    PackageInfo {
+        name: name.to_string(),
+        version: version.to_string(),
+        architecture: arch.to_string(),
+        repository: repository.map(|r| r.to_string()),
+    }
+}
+
+const APT_POLICY: &str = "bash:
+  Installed: 5.1-6ubuntu1
+  Candidate: 5.1-6ubuntu1.1
+  Version table:
+     5.1-6ubuntu1.1 500
+        500 http://archive.ubuntu.com/ubuntu jammy-updates/main amd64 Packages
+ *** 5.1-6ubuntu1 100
+        100 /var/lib/dpkg/status
+nginx:
+  Installed: 1.18.0-6ubuntu14
+  Candidate: 1.18.0-6ubuntu14
+  Version table:
+ *** 1.18.0-6ubuntu14 500
+        500 http://archive.ubuntu.com/ubuntu jammy/main amd64 Packages
+        100 /var/lib/dpkg/status
+";
+
+#[test]
+fn apt_packages() {
+    let b = Mock::new()
+        .on(
+            "dpkg-query -W",
+            "ii \tnginx\t1.18.0-6ubuntu14\tamd64\n\
+             rc \tapache2\t2.4.52-1ubuntu4\tamd64\n\
+             hi \tbash\t5.1-6ubuntu1\tamd64",
+        )
+        .on(
+            "apt list --upgradable",
+            "Listing...\n\
+             bash/jammy-updates,jammy-security 5.1-6ubuntu1.1 amd64 [upgradable from: 5.1-6ubuntu1]",
+        )
+        .on("apt-cache policy bash nginx", APT_POLICY);
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::apt::Apt),
+    };
+
+    assert_eq!(
+        installed(&b, &p).unwrap(),
+        vec![
+            info("bash", "5.1-6ubuntu1", "amd64", None),
+            info("nginx", "1.18.0-6ubuntu14", "amd64", Some("jammy")),
+        ]
+    );
+    assert_eq!(
+        upgradable(&b, &p).unwrap(),
+        vec![info(
+            "bash",
+            "5.1-6ubuntu1.1",
+            "amd64",
+            Some("jammy-updates")
+        )]
+    );
+}
+
+#[test]
+fn dnf_packages() {
+    let b = Mock::new()
+        .on(
+            "dnf -q repoquery --installed",
+            "nginx\t1:1.14.1-9.el8\tx86_64\tappstream\n\
+             gpg-pubkey\t0:8483c65d-5ccc5b19\t(none)\t\n\
+             bash\t0:4.4.20-4.el8\tx86_64\t@System",
+        )
+        .on(
+            "dnf -q check-update",
+            "\n\
+             bash.x86_64                 4.4.20-5.el8             baseos\n\
+             python3-libselinux-with-a-long-name.x86_64\n\
+             \x20                           2.9-8.el8                appstream\n\
+             Obsoleting Packages\n\
+             grub2-tools.x86_64          1:2.02-142.el8           baseos",
+        );
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::dnf::Dnf),
+    };
+
+    assert_eq!(
+        installed(&b, &p).unwrap(),
+        vec![
+            info("bash", "4.4.20-4.el8", "x86_64", Some("@System")),
+            info("nginx", "1:1.14.1-9.el8", "x86_64", Some("appstream")),
+        ]
+    );
+    assert_eq!(
+        upgradable(&b, &p).unwrap(),
+        vec![
+            info("bash", "4.4.20-5.el8", "x86_64", Some("baseos")),
+            info(
+                "python3-libselinux-with-a-long-name",
+                "2.9-8.el8",
+                "x86_64",
+                Some("appstream")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn yum_packages() {
+    let b = Mock::new()
+        .on(
+            "rpm -qa --qf",
+            "nginx\t1:1.20.1-10.el7\tx86_64\n\
+             bash\t4.2.46-34.el7\tx86_64\n\
+             local-tool\t1.0-1\tnoarch",
+        )
+        .on(
+            "yumdb get from_repo",
+            "bash-4.2.46-34.el7.x86_64\n\
+             \x20    from_repo = base\n\
+             \n\
+             1:nginx-1.20.1-10.el7.x86_64\n\
+             \x20    from_repo = epel",
+        );
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::yum::Yum),
+    };
+
+    assert_eq!(
+        installed(&b, &p).unwrap(),
+        vec![
+            info("bash", "4.2.46-34.el7", "x86_64", Some("base")),
+            info("local-tool", "1.0-1", "noarch", None),
+            info("nginx", "1:1.20.1-10.el7", "x86_64", Some("epel")),
+        ]
+    );
+}
+
+#[test]
+fn apt_hold_and_architecture() {
+    let b = Mock::new()
+        .on(
+            "dpkg-query -f '${Status}' -W libc6:i386",
+            "hold ok installed",
+        )
+        .on(
+            "dpkg-query -f '${Status} ${Version}' -W libc6:i386",
+            "2.36-9+deb12u3",
+        )
+        .on("apt-mark", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::apt::Apt),
+    };
+
+    let libc6 = Package::new("libc6", None, &b, &p).with_architecture("i386");
+    assert!(libc6.is_held().unwrap());
+    assert!(libc6.is_installed().unwrap());
+    assert_eq!(libc6.version().unwrap(), "2.36-9+deb12u3");
+    assert!(b
+        .commands()
+        .last()
+        .unwrap()
+        .ends_with("sed -n -E 's/^(install|hold) ok installed //p'"));
+    assert!(!Package::new("nginx", None, &b, &p).is_held().unwrap());
+    assert!(libc6.unhold().unwrap());
+    assert!(Package::new("nginx", None, &b, &p).hold().unwrap());
+
+    let commands = b.commands();
+    assert!(commands.contains(&"apt-mark unhold libc6:i386".to_string()));
+    assert!(commands.contains(&"apt-mark hold nginx".to_string()));
+}
+
+#[test]
+fn architecture_without_multiarch() {
+    let b = Mock::new().on("pacman -Q nginx", "nginx 1.24.0-1");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::pacman::Pacman),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p).with_architecture("i686");
+    assert!(matches!(
+        nginx.is_installed(),
+        Err(Error::HandleFuncNotDefined(_))
+    ));
+    assert!(b.commands().is_empty());
+}
+
+#[test]
+fn dnf_hold_and_architecture() {
+    let b = Mock::new()
+        .on(
+            "dnf -q versionlock list",
+            "# Added lock on Mon Oct 19 10:00:00 2026\n\
+             glibc-0:2.28-225.el8.*\n\
+             !kernel-0:4.18.0-513.el8.*",
+        )
+        .on("rpm -q --qf", "0:2.28-225.el8")
+        .on("dnf -y", "");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::dnf::Dnf),
+    };
+
+    let glibc = Package::new("glibc", None, &b, &p).with_architecture("i686");
+    assert!(glibc.is_held().unwrap());
+    assert!(!Package::new("kernel", None, &b, &p).is_held().unwrap());
+    assert!(glibc.is_installed().unwrap());
+    assert!(glibc.hold().unwrap());
+    assert!(Package::new("glibc", Some("2.28"), &b, &p)
+        .with_architecture("i686")
+        .install()
+        .unwrap());
+
+    let commands = b.commands();
+    assert!(commands.iter().any(|c| c.ends_with("glibc.i686")));
+    assert!(commands.contains(&"dnf -y versionlock add glibc.i686".to_string()));
+    assert!(commands.contains(&"dnf -y install glibc-2.28.i686".to_string()));
+}
+
+#[test]
+fn versionlock_list() {
+    assert_eq!(
+        package::parse_versionlock("0:nginx-1.14.1-9.el7.*\npython3.11-0:3.11.5-1.el8.*"),
+        vec!["nginx".to_string(), "python3.11".to_string()]
+    );
+    assert_eq!(
+        package::split_rpm_architecture("python3.11"),
+        ("python3.11", None)
+    );
+    assert_eq!(
+        package::split_rpm_architecture("glibc.i686"),
+        ("glibc", Some("i686"))
+    );
+}
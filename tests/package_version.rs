@@ -0,0 +1,118 @@
+extern crate specinfra;
+
+mod common;
+
+use std::cmp::Ordering;
+use std::cmp::Ordering::{Equal, Greater, Less};
+
+use common::Mock;
+use specinfra::provider::package;
+use specinfra::provider::package::version;
+use specinfra::provider::package::version::Scheme;
+use specinfra::provider::package::PackageProvider;
+use specinfra::resource::package::Package;
+
+#[test]
+fn debian_comparison() {
+    let cases: &[(&str, &str, Ordering)] = &[
+        ("1.0", "1.0", Equal),
+        ("1.0", "1.0-0", Equal),
+        ("1.0", "1.1", Less),
+        ("1.10", "1.9", Greater),
+        ("1.0~rc1", "1.0", Less),
+        ("1.0~rc1", "1.0~rc2", Less),
+        ("1.0~~", "1.0~", Less),
+        ("1.0+b1", "1.0", Greater),
+        ("1.0a", "1.0", Greater),
+        ("1.0a", "1.0+", Less),
+        ("1:0.9", "2.0", Greater),
+        ("0:1.0", "1.0", Equal),
+        ("2.36-9+deb12u3", "2.36-9+deb12u10", Less),
+        ("1.2.3-1ubuntu1", "1.2.3-1", Greater),
+        ("001.02", "1.2", Equal),
+    ];
+    for (a, b, ord) in cases {
+        assert_eq!(version::compare_debian(a, b), *ord, "{} vs {}", a, b);
+        assert_eq!(
+            version::compare_debian(b, a),
+            ord.reverse(),
+            "{} vs {}",
+            b,
+            a
+        );
+    }
+}
+
+#[test]
+fn rpm_comparison() {
+    let cases: &[(&str, &str, Ordering)] = &[
+        ("1.0", "1.0", Equal),
+        ("1.0", "2.0", Less),
+        ("2.0.1", "2.0", Greater),
+        ("2.0.1a", "2.0.1", Greater),
+        ("5.5p1", "5.5p10", Less),
+        ("10xyz", "10.1xyz", Less),
+        ("xyz10", "xyz10.1", Less),
+        ("xyz.4", "8", Less),
+        ("1.0010", "1.9", Greater),
+        ("1.05", "1.5", Equal),
+        ("1.0~rc1", "1.0", Less),
+        ("1.0~rc1", "1.0~rc2", Less),
+        ("1.0^", "1.0", Greater),
+        ("1.0^git1", "1.0.1", Less),
+        ("1.0^git1", "1.0~rc1", Greater),
+        ("a+", "a_", Equal),
+        ("1:1.0-1", "2.0-1", Greater),
+        ("1.14.1-9.el8", "1.14.1-10.el8", Less),
+        ("1.14.1-9.el8", "1.14.1", Equal),
+    ];
+    for (a, b, ord) in cases {
+        assert_eq!(version::compare_rpm(a, b), *ord, "{} vs {}", a, b);
+        assert_eq!(version::compare_rpm(b, a), ord.reverse(), "{} vs {}", b, a);
+    }
+}
+
+#[test]
+fn requirements() {
+    assert!(version::satisfies("1.2.3", ">= 1.2.3, < 2", Scheme::Debian).unwrap());
+    assert!(!version::satisfies("2.0", ">= 1.2.3, < 2", Scheme::Debian).unwrap());
+    assert!(!version::satisfies("2~rc1", ">= 2", Scheme::Debian).unwrap());
+    assert!(version::satisfies("2~rc1", "<< 2", Scheme::Debian).unwrap());
+    assert!(version::satisfies("1.0", "1.0", Scheme::Rpm).unwrap());
+    assert!(version::satisfies("1.0-3.el9", "= 1.0", Scheme::Rpm).unwrap());
+    assert!(version::satisfies("1.0-3.el9", "!= 1.1", Scheme::Rpm).unwrap());
+    assert!(!version::satisfies("", ">= 0", Scheme::Rpm).unwrap());
+
+    assert!(version::satisfies("1.0", ">=", Scheme::Rpm).is_err());
+    assert!(version::satisfies("1.0", "=> 1.0", Scheme::Rpm).is_err());
+}
+
+#[test]
+fn apt_version_satisfies() {
+    let b = Mock::new().on("dpkg-query", "1:1.22.1-9");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::apt::Apt),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.version_satisfies(">= 1:1.22, < 1:1.23").unwrap());
+    assert!(!nginx.version_satisfies("< 1.24").unwrap());
+}
+
+#[test]
+fn dnf_version_satisfies() {
+    let b = Mock::new().on("rpm -q", "1:1.14.1-9.module_el8.0.0+184+e34b5b83");
+    let p = PackageProvider {
+        inline: Box::new(package::inline::null::Null),
+        shell: Box::new(package::shell::dnf::Dnf),
+    };
+
+    let nginx = Package::new("nginx", None, &b, &p);
+    assert!(nginx.version_satisfies(">= 1:1.14, < 1:1.16").unwrap());
+    assert!(!nginx.version_satisfies(">= 1:1.16").unwrap());
+
+    let empty = Mock::new();
+    let missing = Package::new("httpd", None, &empty, &p);
+    assert!(!missing.version_satisfies(">= 0").unwrap());
+}
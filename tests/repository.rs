@@ -0,0 +1,178 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::repository;
+use specinfra::provider::repository::RepositoryProvider;
+use specinfra::resource::repository::Repository;
+
+const SOURCES_LIST: &str = "
+# See http://help.ubuntu.com/community/UpgradeNotes
+deb http://archive.ubuntu.com/ubuntu/ jammy main restricted
+deb [arch=amd64 signed-by=/usr/share/keyrings/docker.gpg] https://download.docker.com/linux/ubuntu jammy stable
+# deb http://archive.canonical.com/ubuntu jammy partner
+deb https://ppa.launchpadcontent.net/deadsnakes/ppa/ubuntu/ jammy main
+";
+
+const SOURCES: &str = "
+Types: deb
+URIs: https://packages.microsoft.com/repos/code
+Suites: stable
+Components: main
+
+# Disabled
+Types: deb deb-src
+URIs: http://ppa.launchpad.net/git-core/ppa/ubuntu
+Suites: jammy
+Components: main
+Enabled: no
+";
+
+const YUM_REPOS: &str = "
+[baseos]
+name=BaseOS
+mirrorlist=http://mirrorlist.centos.org/?release=8&arch=x86_64&repo=BaseOS
+gpgcheck=1
+
+[epel]
+name=Extra Packages for Enterprise Linux
+baseurl=https://dl.fedoraproject.org/pub/epel/8/Everything/x86_64/
+enabled = 1
+
+[epel-testing]
+name=Extra Packages for Enterprise Linux - Testing
+baseurl=
+  https://dl.fedoraproject.org/pub/epel/testing/8/Everything/x86_64/
+enabled=0
+";
+
+#[test]
+fn apt_repository() {
+    let b = Mock::new()
+        .on("sources.list.d/*.list", SOURCES_LIST)
+        .on("sources.list.d/*.sources", SOURCES)
+        .on("add-apt-repository", "");
+    let p = RepositoryProvider::new(
+        Box::new(repository::inline::null::Null),
+        Box::new(repository::shell::apt::Apt),
+    );
+
+    let ubuntu = Repository::new("http://archive.ubuntu.com/ubuntu", &b, &p);
+    assert!(ubuntu.exists().unwrap());
+    assert!(ubuntu.is_enabled().unwrap());
+
+    let docker = Repository::new("https://download.docker.com/linux/ubuntu", &b, &p);
+    assert!(docker.is_enabled().unwrap());
+
+    let partner = Repository::new("http://archive.canonical.com/ubuntu", &b, &p);
+    assert!(partner.exists().unwrap());
+    assert!(!partner.is_enabled().unwrap());
+
+    let deadsnakes = Repository::new("ppa:deadsnakes/ppa", &b, &p);
+    assert!(deadsnakes.exists().unwrap());
+    assert!(deadsnakes.is_enabled().unwrap());
+
+    let vscode = Repository::new("https://packages.microsoft.com/repos/code", &b, &p);
+    assert!(vscode.is_enabled().unwrap());
+
+    let git = Repository::new("ppa:git-core/ppa", &b, &p);
+    assert!(git.exists().unwrap());
+    assert!(!git.is_enabled().unwrap());
+
+    let missing = Repository::new("ppa:ondrej/php", &b, &p);
+    assert!(!missing.exists().unwrap());
+    assert!(!missing.is_enabled().unwrap());
+
+    assert!(missing.add().unwrap());
+    assert!(missing.remove().unwrap());
+    let commands = b.commands();
+    assert_eq!(
+        commands[commands.len() - 2],
+        "add-apt-repository -y 'ppa:ondrej/php'"
+    );
+    assert_eq!(
+        commands[commands.len() - 1],
+        "add-apt-repository -y -r 'ppa:ondrej/php'"
+    );
+}
+
+#[test]
+fn yum_repository() {
+    let b = Mock::new()
+        .on("cat /etc/yum.repos.d/*.repo", YUM_REPOS)
+        .on("yum-config-manager --add-repo", "");
+    let p = RepositoryProvider::new(
+        Box::new(repository::inline::null::Null),
+        Box::new(repository::shell::yum::Yum),
+    );
+
+    let baseos = Repository::new("baseos", &b, &p);
+    assert!(baseos.exists().unwrap());
+    assert!(baseos.is_enabled().unwrap());
+
+    let epel = Repository::new(
+        "https://dl.fedoraproject.org/pub/epel/8/Everything/x86_64",
+        &b,
+        &p,
+    );
+    assert!(epel.exists().unwrap());
+    assert!(epel.is_enabled().unwrap());
+
+    let testing = Repository::new("epel-testing", &b, &p);
+    assert!(testing.exists().unwrap());
+    assert!(!testing.is_enabled().unwrap());
+
+    let docker = Repository::new(
+        "https://download.docker.com/linux/centos/docker-ce.repo",
+        &b,
+        &p,
+    );
+    assert!(!docker.exists().unwrap());
+    assert!(docker.add().unwrap());
+    assert_eq!(
+        b.commands().last().unwrap(),
+        "yum-config-manager --add-repo 'https://download.docker.com/linux/centos/docker-ce.repo'"
+    );
+
+    // Nothing defines it, so there is nothing to remove.
+    assert!(!docker.remove().unwrap());
+}
+
+const CENTOS_BASE_REPO: &str = "
+[base]
+name=CentOS-$releasever - Base
+baseurl = http://mirror.centos.org/centos/$releasever/os/$basearch/
+
+[updates]
+name=CentOS-$releasever - Updates
+baseurl = http://mirror.centos.org/centos/$releasever/updates/$basearch/
+
+[extras]
+name=CentOS-$releasever - Extras
+baseurl = http://mirror.centos.org/centos/$releasever/extras/$basearch/
+";
+
+#[test]
+fn yum_repository_remove_keeps_other_sections() {
+    let b = Mock::new()
+        .on("cat /etc/yum.repos.d/*.repo", CENTOS_BASE_REPO)
+        .on("sed -i", "");
+    let p = RepositoryProvider::new(
+        Box::new(repository::inline::null::Null),
+        Box::new(repository::shell::yum::Yum),
+    );
+
+    let updates = Repository::new(
+        "http://mirror.centos.org/centos/$releasever/updates/$basearch",
+        &b,
+        &p,
+    );
+    assert!(updates.remove().unwrap());
+    assert_eq!(
+        b.commands().last().unwrap(),
+        "for f in $(grep -l -x -F '[updates]' /etc/yum.repos.d/*.repo); do \
+         sed -i '/^\\[updates\\]$/,/^\\[/{/^\\[updates\\]$/d;/^\\[/!d}' \"$f\"; done"
+    );
+    assert!(!b.commands().iter().any(|c| c.contains("rm ")));
+}
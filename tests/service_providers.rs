@@ -0,0 +1,668 @@
+extern crate specinfra;
+
+mod common;
+
+use common::Mock;
+use specinfra::provider::error::Error;
+use specinfra::provider::service;
+use specinfra::provider::service::error::JobError;
+use specinfra::provider::service::error::JobResult;
+use specinfra::provider::service::inline::systemd;
+use specinfra::provider::service::Scope;
+use specinfra::provider::service::ServiceProvider;
+use specinfra::resource::service::daemon_reload;
+use specinfra::resource::service::services;
+use specinfra::resource::service::LogEntry;
+use specinfra::resource::service::Service;
+use specinfra::resource::service::ServiceInfo;
+use std::time::Duration;
+
+const SSHD: &str = "system/com.openssh.sshd = {
+	active count = 1
+	path = /System/Library/LaunchDaemons/ssh.plist
+	state = running
+
+	program = /usr/libexec/sshd-keygen-wrapper
+}";
+
+const NGINX: &str = "system/homebrew.mxcl.nginx = {
+	active count = 0
+	path = /Library/LaunchDaemons/homebrew.mxcl.nginx.plist
+	state = not running
+}";
+
+const DISABLED: &str = "disabled services = {
+	\"com.apple.ftpd\" => disabled
+	\"com.openssh.sshd\" => enabled
+	\"homebrew.mxcl.nginx\" => disabled
+}";
+
+#[test]
+fn launchd_service() {
+    let b = Mock::new()
+        .on("launchctl print system/com.openssh.sshd", SSHD)
+        .on("launchctl print system/homebrew.mxcl.nginx", NGINX)
+        .on("launchctl print-disabled system", DISABLED)
+        .on("launchctl enable", "")
+        .on("launchctl kickstart", "")
+        .on("launchctl bootout", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::launchd::Launchd),
+    );
+
+    let sshd = Service::new("com.openssh.sshd", &b, &p);
+    assert!(sshd.is_running().unwrap());
+    assert!(sshd.is_enabled().unwrap());
+
+    let nginx = Service::new("homebrew.mxcl.nginx", &b, &p);
+    assert!(!nginx.is_running().unwrap());
+    assert!(!nginx.is_enabled().unwrap());
+
+    let ftpd = Service::new("com.apple.ftpd", &b, &p);
+    assert!(!ftpd.is_running().unwrap());
+    assert!(!ftpd.is_enabled().unwrap());
+
+    assert!(nginx.enable().unwrap());
+    assert!(nginx.start().unwrap());
+    assert!(nginx.restart().unwrap());
+    assert!(nginx.reload().unwrap());
+    assert!(nginx.stop().unwrap());
+
+    let commands = b.commands();
+    let commands = &commands[commands.len() - 5..];
+    assert_eq!(
+        commands,
+        [
+            "launchctl enable system/homebrew.mxcl.nginx",
+            "launchctl print system/homebrew.mxcl.nginx > /dev/null 2>&1 \
+             || launchctl bootstrap system /Library/LaunchDaemons/homebrew.mxcl.nginx.plist \
+             && launchctl kickstart system/homebrew.mxcl.nginx",
+            "launchctl kickstart -k system/homebrew.mxcl.nginx",
+            "launchctl kickstart -k system/homebrew.mxcl.nginx",
+            "launchctl bootout system/homebrew.mxcl.nginx",
+        ]
+    );
+}
+
+#[test]
+fn openrc_service() {
+    let b = Mock::new()
+        .on("rc-service sshd status", " * status: started")
+        .on("test -e /etc/runlevels/default/sshd", "")
+        .on("rc-update", "")
+        .on("rc-service nginx", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::openrc::OpenRc),
+    );
+
+    let sshd = Service::new("sshd", &b, &p);
+    assert!(sshd.is_running().unwrap());
+    assert!(sshd.is_enabled().unwrap());
+
+    let nginx = Service::new("nginx", &b, &p);
+    assert!(!nginx.is_enabled().unwrap());
+    assert!(nginx.enable().unwrap());
+    assert!(nginx.start().unwrap());
+    assert!(nginx.reload().unwrap());
+    assert!(nginx.disable().unwrap());
+
+    let commands = b.commands();
+    assert_eq!(
+        &commands[commands.len() - 4..],
+        [
+            "rc-update add nginx default",
+            "rc-service nginx start",
+            "rc-service nginx reload",
+            "rc-update del nginx default",
+        ]
+    );
+}
+
+#[test]
+fn runit_service() {
+    let b = Mock::new()
+        .on(
+            "sv status /var/service/sshd",
+            "run: /var/service/sshd: (pid 412) 3617s",
+        )
+        .on(
+            "sv status /var/service/nginx",
+            "down: /var/service/nginx: 12s, normally up",
+        )
+        .on("test -e /var/service/sshd", "")
+        .on("ln -s", "")
+        .on("sv restart", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::runit::Runit {
+            service_dir: "/var/service",
+        }),
+    );
+
+    let sshd = Service::new("sshd", &b, &p);
+    assert!(sshd.is_running().unwrap());
+    assert!(sshd.is_enabled().unwrap());
+
+    let nginx = Service::new("nginx", &b, &p);
+    assert!(!nginx.is_running().unwrap());
+    assert!(!nginx.is_enabled().unwrap());
+    assert!(nginx.enable().unwrap());
+    assert!(nginx.restart().unwrap());
+
+    let commands = b.commands();
+    assert_eq!(
+        &commands[commands.len() - 2..],
+        [
+            "ln -s /etc/sv/nginx /var/service/nginx",
+            "sv restart /var/service/nginx",
+        ]
+    );
+}
+
+#[test]
+fn s6_service() {
+    let b = Mock::new()
+        .on("s6-svstat -o up /run/service/sshd", "true")
+        .on("s6-svstat -o up /run/service/nginx", "false")
+        .on("s6-rc-db contents default", "sshd\nsyslogd")
+        .on("s6-rc-bundle-update", "")
+        .on("s6-rc -u change nginx", "")
+        .on("s6-svc", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::s6::S6),
+    );
+
+    let sshd = Service::new("sshd", &b, &p);
+    assert!(sshd.is_running().unwrap());
+    assert!(sshd.is_enabled().unwrap());
+
+    let nginx = Service::new("nginx", &b, &p);
+    assert!(!nginx.is_running().unwrap());
+    assert!(!nginx.is_enabled().unwrap());
+    assert!(nginx.enable().unwrap());
+    assert!(nginx.start().unwrap());
+    assert!(nginx.reload().unwrap());
+
+    let commands = b.commands();
+    assert_eq!(
+        &commands[commands.len() - 3..],
+        [
+            "s6-rc-bundle-update add default nginx",
+            "s6-rc -u change nginx",
+            "s6-svc -h /run/service/nginx",
+        ]
+    );
+}
+
+#[test]
+fn systemd_service_properties() {
+    let b = Mock::new()
+        .on("TZ=UTC systemctl show -p SubState sshd", "SubState=running")
+        .on("TZ=UTC systemctl show -p MainPID sshd", "MainPID=812")
+        .on(
+            "TZ=UTC systemctl show -p Restart sshd",
+            "Restart=on-failure",
+        )
+        .on(
+            "TZ=UTC systemctl show -p ExecStart sshd",
+            "ExecStart={ path=/usr/sbin/sshd ; argv[]=/usr/sbin/sshd -D $SSHD_OPTS ; \
+             ignore_errors=no ; start_time=[Mon 2026-10-19 10:00:00 UTC] ; \
+             stop_time=[n/a] ; pid=812 ; code=(null) ; status=0/0 }",
+        )
+        .on("TZ=UTC systemctl show -p User sshd", "User=")
+        .on(
+            "TZ=UTC systemctl show -p Environment sshd",
+            "Environment=LANG=C \"SSHD_OPTS=-o LogLevel=VERBOSE\"",
+        )
+        .on("TZ=UTC systemctl show -p NRestarts sshd", "NRestarts=2")
+        .on(
+            "TZ=UTC systemctl show -p ActiveEnterTimestamp sshd",
+            "ActiveEnterTimestamp=Mon 2026-10-19 10:00:00 UTC",
+        );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    let sshd = Service::new("sshd", &b, &p);
+    assert_eq!(sshd.sub_state().unwrap(), "running");
+    assert_eq!(sshd.main_pid().unwrap(), 812);
+    assert_eq!(sshd.restart_policy().unwrap(), "on-failure");
+    assert_eq!(sshd.exec_start().unwrap(), ["/usr/sbin/sshd -D $SSHD_OPTS"]);
+    assert_eq!(sshd.user().unwrap(), "");
+    assert_eq!(
+        sshd.environment().unwrap(),
+        ["LANG=C", "SSHD_OPTS=-o LogLevel=VERBOSE"]
+    );
+    assert_eq!(sshd.n_restarts().unwrap(), 2);
+    assert_eq!(
+        sshd.active_enter_timestamp().unwrap(),
+        1_792_404_000_000_000
+    );
+}
+
+#[test]
+fn systemd_unit_types() {
+    assert_eq!(systemd::unit_name("sshd"), "sshd.service");
+    assert_eq!(systemd::unit_name("sshd.service"), "sshd.service");
+    assert_eq!(systemd::unit_name("logrotate.timer"), "logrotate.timer");
+    assert_eq!(systemd::unit_name("docker.socket"), "docker.socket");
+    assert_eq!(systemd::unit_name("multi-user.target"), "multi-user.target");
+    assert_eq!(systemd::unit_name("php8.2-fpm"), "php8.2-fpm.service");
+
+    let b = Mock::new()
+        .on(
+            "TZ=UTC systemctl show -p NextElapseUSecRealtime logrotate.timer",
+            "NextElapseUSecRealtime=Tue 2026-10-20 00:00:00 UTC",
+        )
+        .on(
+            "TZ=UTC systemctl show -p LastTriggerUSec logrotate.timer",
+            "LastTriggerUSec=n/a",
+        )
+        .on(
+            "TZ=UTC systemctl show -p Listen docker.socket",
+            "Listen=/run/docker.sock (Stream)\nListen=[::]:2375 (Stream)",
+        )
+        .on(
+            "TZ=UTC systemctl show -p Wants multi-user.target",
+            "Wants=sshd.service cron.service",
+        );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    let timer = Service::new("logrotate.timer", &b, &p);
+    assert_eq!(timer.next_elapse().unwrap(), 1_792_454_400_000_000);
+    assert_eq!(timer.last_trigger().unwrap(), 0);
+
+    let socket = Service::new("docker.socket", &b, &p);
+    assert_eq!(
+        socket.listen_addresses().unwrap(),
+        ["/run/docker.sock", "[::]:2375"]
+    );
+
+    let target = Service::new("multi-user.target", &b, &p);
+    assert_eq!(target.wants().unwrap(), ["sshd.service", "cron.service"]);
+}
+
+#[test]
+fn systemd_user_service() {
+    let b = Mock::new()
+        .on(
+            "machinectl shell -q --uid=1000 .host /usr/bin/env systemctl --user is-active syncthing",
+            "active",
+        )
+        .on(
+            "machinectl shell -q --uid=1000 .host /usr/bin/env TZ=UTC systemctl --user show -p MainPID syncthing",
+            "MainPID=2301",
+        )
+        .on("machinectl shell -q --uid=1000 .host", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    let syncthing = Service::new("syncthing", &b, &p).with_scope(Scope::User(1000));
+    assert!(syncthing.is_running().unwrap());
+    assert_eq!(syncthing.main_pid().unwrap(), 2301);
+    assert!(syncthing.restart().unwrap());
+    assert!(!Service::new("syncthing", &b, &p).is_running().unwrap());
+
+    let commands = b.commands();
+    assert_eq!(
+        commands[2],
+        "machinectl shell -q --uid=1000 .host /usr/bin/env systemctl --user restart syncthing"
+    );
+
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::openrc::OpenRc),
+    );
+    assert!(Service::new("syncthing", &b, &p)
+        .with_scope(Scope::User(1000))
+        .is_running()
+        .is_err());
+}
+
+#[test]
+fn systemd_job_error() {
+    assert_eq!(JobResult::from("dependency"), JobResult::Dependency);
+    assert_eq!(
+        JobResult::from("once"),
+        JobResult::Other("once".to_string())
+    );
+
+    let e = JobError {
+        unit: "nginx.service".to_string(),
+        result: JobResult::from("failed"),
+        journal: vec![
+            "nginx: [emerg] unknown directive \"lsten\"".to_string(),
+            "nginx: configuration file /etc/nginx/nginx.conf test failed".to_string(),
+        ],
+    };
+    assert_eq!(
+        e.to_string(),
+        "Job for nginx.service failed\n\
+         nginx: [emerg] unknown directive \"lsten\"\n\
+         nginx: configuration file /etc/nginx/nginx.conf test failed"
+    );
+}
+
+#[test]
+fn systemd_job_wait_timeout() {
+    let removed = vec![
+        Some((
+            "/org/freedesktop/systemd1/job/7".to_string(),
+            "done".to_string(),
+        )),
+        None,
+        Some((
+            "/org/freedesktop/systemd1/job/8".to_string(),
+            "failed".to_string(),
+        )),
+    ];
+    assert_eq!(
+        systemd::wait_for_job(
+            removed.into_iter(),
+            "/org/freedesktop/systemd1/job/8",
+            "nginx.service",
+            Duration::from_secs(90),
+        )
+        .unwrap(),
+        "failed"
+    );
+
+    match systemd::wait_for_job(
+        std::iter::repeat(None),
+        "/org/freedesktop/systemd1/job/8",
+        "nginx.service",
+        Duration::ZERO,
+    ) {
+        Err(service::error::Error::JobWaitTimeout { unit, timeout }) => {
+            assert_eq!(unit, "nginx.service");
+            assert_eq!(timeout, Duration::ZERO);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let p = systemd::Systemd {
+        job_timeout: Duration::from_secs(5),
+    };
+    assert_eq!(p.job_timeout, Duration::from_secs(5));
+    assert_eq!(
+        systemd::Systemd::default().job_timeout,
+        Duration::from_secs(90)
+    );
+}
+
+#[test]
+fn systemd_shell_job_failure() {
+    let b = Mock::new().on(
+        "journalctl -u nginx.service -n 10",
+        "nginx: [emerg] unknown directive \"lsten\"\n\
+         nginx: configuration file /etc/nginx/nginx.conf test failed",
+    );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    match Service::new("nginx", &b, &p).start() {
+        Err(Error::Service(service::error::Error::Job(e))) => {
+            assert_eq!(e.unit, "nginx.service");
+            assert_eq!(e.result, JobResult::Failed);
+            assert_eq!(
+                e.journal,
+                vec![
+                    "nginx: [emerg] unknown directive \"lsten\"",
+                    "nginx: configuration file /etc/nginx/nginx.conf test failed",
+                ]
+            );
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+    assert_eq!(b.commands()[0], "systemctl start nginx");
+}
+
+#[test]
+fn systemd_service_logs() {
+    let b = Mock::new().on(
+        "journalctl -u nginx -o json --no-pager -n 2 --since '1 hour ago'",
+        "{\"PRIORITY\":\"3\",\"__REALTIME_TIMESTAMP\":\"1792404000123456\",\
+          \"MESSAGE\":\"nginx: [emerg] bind() to 0.0.0.0:80 failed\"}\n\
+         {\"PRIORITY\":\"6\",\"__REALTIME_TIMESTAMP\":\"1792404000200000\",\
+          \"MESSAGE\":[110,103,105,110,120,255]}",
+    );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    let logs = Service::new("nginx", &b, &p)
+        .logs(Some("1 hour ago"), 2)
+        .unwrap();
+    assert_eq!(
+        logs,
+        [
+            LogEntry {
+                priority: Some(3),
+                timestamp: Some(1_792_404_000_123_456),
+                message: "nginx: [emerg] bind() to 0.0.0.0:80 failed".to_string(),
+            },
+            LogEntry {
+                priority: Some(6),
+                timestamp: Some(1_792_404_000_200_000),
+                message: "nginx\u{fffd}".to_string(),
+            },
+        ]
+    );
+
+    let _ = Service::new("nginx", &b, &p).logs(Some("2026-10-19' ; reboot '"), 2);
+    assert_eq!(
+        b.commands()[1],
+        "journalctl -u nginx -o json --no-pager -n 2 --since '2026-10-19'\\'' ; reboot '\\'''"
+    );
+}
+
+#[test]
+fn sysvinit_service_logs() {
+    let b = Mock::new()
+        .on("tail -q -n 10", "starting\nlistening on :8080")
+        .on(
+            "tail -q -n 2",
+            "starting\nlistening on :8080\nfailed to open db\nexiting",
+        );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::sysvinit::SysVInit),
+    );
+
+    let app = Service::new("app", &b, &p)
+        .with_log_files(&["/srv/app/log/app.log", "/srv/app/log/error.log"]);
+    let logs = app.logs(None, 10).unwrap();
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[1].message, "listening on :8080");
+    assert_eq!(logs[1].priority, None);
+
+    // Both files give two lines, of which the last two are kept.
+    let logs = app.logs(None, 2).unwrap();
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].message, "failed to open db");
+
+    let _ = Service::new("app", &b, &p).logs(None, 10);
+    let commands = b.commands();
+    assert_eq!(
+        commands,
+        [
+            "tail -q -n 10 '/srv/app/log/app.log' '/srv/app/log/error.log'",
+            "tail -q -n 2 '/srv/app/log/app.log' '/srv/app/log/error.log'",
+            "tail -q -n 10 '/var/log/app.log'",
+        ]
+    );
+
+    match app.logs(Some("1 hour ago"), 10) {
+        Err(Error::Service(service::error::Error::NotSupported { operation, init })) => {
+            assert_eq!(operation, "logs since");
+            assert_eq!(init, "SysVInit");
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let b = Mock::new();
+    assert!(Service::new("app", &b, &p)
+        .with_log_files(&["/srv/app/log/missing.log"])
+        .logs(None, 10)
+        .is_err());
+}
+
+fn info(name: &str, running: bool, enabled: bool) -> ServiceInfo {
+    ServiceInfo {
+        name: name.to_string(),
+        running,
+        enabled,
+    }
+}
+
+#[test]
+fn systemd_services() {
+    let b = Mock::new()
+        .on(
+            "systemctl list-units --type=service --all --output=json",
+            r#"[{"unit":"cron.service","load":"loaded","active":"active","sub":"running"},
+                {"unit":"nginx.service","load":"loaded","active":"failed","sub":"failed"}]"#,
+        )
+        .on(
+            "systemctl list-unit-files --type=service --output=json",
+            r#"[{"unit_file":"cron.service","state":"enabled"},
+                {"unit_file":"getty@.service","state":"enabled"},
+                {"unit_file":"nginx.service","state":"enabled"},
+                {"unit_file":"rsync.service","state":"disabled"}]"#,
+        );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    assert_eq!(
+        services(&b, &p).unwrap(),
+        [
+            info("cron.service", true, true),
+            info("nginx.service", false, true),
+            info("rsync.service", false, false),
+        ]
+    );
+}
+
+#[test]
+fn sysvinit_services() {
+    let b = Mock::new()
+        .on(
+            "chkconfig --list",
+            "auditd         \t0:off\t1:off\t2:on\t3:on\t4:on\t5:on\t6:off\n\
+             ip6tables      \t0:off\t1:off\t2:on\t3:off\t4:on\t5:on\t6:off\n\
+             sshd           \t0:off\t1:off\t2:on\t3:on\t4:on\t5:on\t6:off\n\
+             \n\
+             xinetd based services:\n\
+             \tchargen-dgram: \toff",
+        )
+        .on(
+            "service --status-all",
+            "auditd (pid  1234) is running...\n\
+             ip6tables: Firewall is not running.\n\
+             sshd is stopped",
+        );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::sysvinit::SysVInit),
+    );
+
+    assert_eq!(
+        services(&b, &p).unwrap(),
+        [
+            info("auditd", true, true),
+            info("ip6tables", false, false),
+            info("sshd", false, true),
+        ]
+    );
+}
+
+#[test]
+fn openrc_services() {
+    let b = Mock::new().on(
+        "rc-status --all",
+        "Runlevel: boot\n \
+         hostname                                  [  started  ]\n\
+         Runlevel: default\n \
+         sshd                                      [  started  ]\n \
+         crond                                     [  stopped  ]\n\
+         Dynamic Runlevel: hotplugged\n\
+         Dynamic Runlevel: needed/wanted\n \
+         localmount                                [  started  ]\n\
+         Dynamic Runlevel: manual\n \
+         nginx                                     [  started  ]",
+    );
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::openrc::OpenRc),
+    );
+
+    assert_eq!(
+        services(&b, &p).unwrap(),
+        [
+            info("crond", false, true),
+            info("hostname", true, false),
+            info("localmount", true, false),
+            info("nginx", true, false),
+            info("sshd", true, true),
+        ]
+    );
+}
+
+#[test]
+fn systemd_mask() {
+    let b = Mock::new()
+        .on("systemctl is-enabled ctrl-alt-del.target", "masked")
+        .on("systemctl is-enabled sshd", "enabled")
+        .on("systemctl mask", "")
+        .on("systemctl unmask", "")
+        .on("systemctl daemon-reload", "");
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::systemd::Systemd),
+    );
+
+    let target = Service::new("ctrl-alt-del.target", &b, &p);
+    assert!(target.is_masked().unwrap());
+    assert!(!Service::new("sshd", &b, &p).is_masked().unwrap());
+    assert!(target.unmask().unwrap());
+    assert!(target.mask().unwrap());
+    assert!(daemon_reload(&b, &p).unwrap());
+
+    let commands = b.commands();
+    assert_eq!(
+        &commands[commands.len() - 3..],
+        [
+            "systemctl unmask ctrl-alt-del.target",
+            "systemctl mask ctrl-alt-del.target",
+            "systemctl daemon-reload",
+        ]
+    );
+}
+
+#[test]
+fn upstart_mask_not_supported() {
+    let b = Mock::new();
+    let p = ServiceProvider::new(
+        Box::new(service::inline::null::Null),
+        Box::new(service::shell::ubuntu_init::UbuntuInit),
+    );
+
+    let e = Service::new("ssh", &b, &p).mask().unwrap_err();
+    assert_eq!(e.to_string(), "mask is not supported by Upstart");
+    assert!(daemon_reload(&b, &p).is_err());
+    assert!(b.commands().is_empty());
+}